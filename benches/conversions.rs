@@ -0,0 +1,64 @@
+//! Benchmark of the conversions between [`bit_fields::Pesel`] and [`human_redable::Pesel`].
+//!
+//! Run with `cargo bench --bench conversions`. Besides the timings, it asserts that the
+//! conversions don't allocate, validation being skipped is covered by the unit tests converting
+//! unchecked PESELs.
+
+use pesel_rs::{bit_fields, human_redable};
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    hint::black_box,
+    sync::atomic::{AtomicUsize, Ordering},
+    time::Instant,
+};
+
+struct CountingAllocator;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+const ITERATIONS: u32 = 10_000_000;
+
+fn bench(name: &str, f: impl Fn()) {
+    let allocations = ALLOCATIONS.load(Ordering::Relaxed);
+    let start = Instant::now();
+    for _ in 0..ITERATIONS {
+        f();
+    }
+    let elapsed = start.elapsed();
+    assert_eq!(
+        ALLOCATIONS.load(Ordering::Relaxed),
+        allocations,
+        "{name} allocated"
+    );
+
+    println!(
+        "{name}: {:.2} ns/iter",
+        elapsed.as_nanos() as f64 / ITERATIONS as f64
+    );
+}
+
+fn main() {
+    let human = human_redable::Pesel::try_from(2290486168).unwrap();
+    let bit = bit_fields::Pesel::try_from(2290486168).unwrap();
+
+    bench("bit_fields -> human_redable", || {
+        black_box(human_redable::Pesel::from(black_box(&bit)));
+    });
+    bench("human_redable -> bit_fields", || {
+        black_box(bit_fields::Pesel::from(black_box(&human)));
+    });
+}
@@ -2,8 +2,8 @@ use super::*;
 
 pub const CONTROL_SECTION_SIZE: u8 = 4;
 pub const ORDINAL_SECTION_SIZE: u8 = 14;
-pub const DAY_SECTION_SIZE: u8 = 7;
-pub const MONTH_SECTION_SIZE: u8 = 5;
+pub const DAY_SECTION_SIZE: u8 = 5;
+pub const MONTH_SECTION_SIZE: u8 = 7;
 pub const YEAR_SECTION_SIZE: u8 = 7;
 
 pub const CONTROL_SECTION_SHIFT: u8 = 0;
@@ -12,6 +12,9 @@ pub const DAY_SECTION_SHIFT: u8 = ORDINAL_SECTION_SHIFT + ORDINAL_SECTION_SIZE +
 pub const MONTH_SECTION_SHIFT: u8 = DAY_SECTION_SHIFT + DAY_SECTION_SIZE + 5;
 pub const YEAR_SECTION_SHIFT: u8 = MONTH_SECTION_SHIFT + MONTH_SECTION_SIZE + 5;
 
+/// Number of significant bits of the packed representation, see [`Pesel::packed_bits`].
+pub const BITS_USED: u8 = YEAR_SECTION_SHIFT + YEAR_SECTION_SIZE;
+
 /// Stores each section of the PESEL in the following layout:
 ///
 /// `------------------------------------------------------------------------`
@@ -22,11 +25,51 @@ pub const YEAR_SECTION_SHIFT: u8 = MONTH_SECTION_SHIFT + MONTH_SECTION_SIZE + 5;
 /// You can get the human readable number using `u64::from`.
 ///
 /// Used when frequently reading individual fields.
-#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord)]
 pub struct Pesel(u64);
 
+impl Pesel {
+    /// Create a PESEL from a human readable number without validating it, keeping only its last
+    /// 11 digits.
+    ///
+    /// Accessors such as [`PeselTrait::date_of_birth`] may panic if `value` isn't a valid PESEL.
+    /// The `checked_*` accessors, [`PeselTrait::validate_self`] and
+    /// [`PeselTrait::control_matches`] probe the contents without panicking, for a valid PESEL
+    /// they never report a problem.
+    pub fn new_unchecked(value: u64) -> Self {
+        Self(
+            ((crate::day_section(value) as u64) << DAY_SECTION_SHIFT as u64)
+                + ((crate::month_section(value) as u64) << MONTH_SECTION_SHIFT as u64)
+                + ((crate::year_section(value) as u64) << YEAR_SECTION_SHIFT as u64)
+                + ((crate::ordinal_section(value) as u64) << ORDINAL_SECTION_SHIFT as u64)
+                + ((crate::control_section(value) as u64) << CONTROL_SECTION_SHIFT as u64),
+        )
+    }
+
+    /// Copy of the PESEL with a different date of birth, keeping the whole ordinal section, see
+    /// [`crate::human_redable::Pesel::with_birth_date_keeping_serial`].
+    #[cfg(feature = "chrono")]
+    pub fn with_birth_date_keeping_serial(&self, date: NaiveDate) -> Result<Self, ValidationError> {
+        crate::human_redable::Pesel::from(self)
+            .with_birth_date_keeping_serial(date)
+            .map(Self::from)
+    }
+
+    /// Raw packed representation, only the lowest [`BITS_USED`] bits are ever set.
+    pub const fn packed_bits(&self) -> u64 {
+        self.0
+    }
+
+    /// Human readable number of the PESEL, same as `u64::from`.
+    pub fn to_u64(&self) -> u64 {
+        u64::from(self)
+    }
+}
+
 impl_try_from_str_for_pesel!(Pesel);
 
+/// Serializes as a number, unlike [`crate::human_redable::Pesel`], to stay compatible with
+/// previously serialized data.
 #[cfg(feature = "serde")]
 impl serde::Serialize for Pesel {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
@@ -56,35 +99,150 @@ impl From<&Pesel> for u64 {
     }
 }
 
+/// Hashes the human readable number, agreeing with [`crate::human_redable::Pesel`].
+impl std::hash::Hash for Pesel {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        u64::from(self).hash(state);
+    }
+}
+
+/// Formats the decomposed fields, e.g.
+/// `Pesel { year: 2002, month: 9, day: 4, serial: 861, gender: Female }`.
+impl std::fmt::Debug for Pesel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Pesel")
+            .field("year", &self.year())
+            .field("month", &crate::month(self).unwrap_or_default())
+            .field("day", &self.day())
+            .field("serial", &self.serial_number())
+            .field("gender", &self.gender())
+            .finish()
+    }
+}
+
+/// Formats the PESEL as an 11 digit number, keeping the leading zeros.
+impl std::fmt::Display for Pesel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:011}", u64::from(self))
+    }
+}
+
+/// Formats the [`Pesel::packed_bits`] in hex, e.g. `{:#x}` for debugging the layout.
+impl std::fmt::LowerHex for Pesel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::LowerHex::fmt(&self.0, f)
+    }
+}
+
 impl TryFrom<u64> for Pesel {
     type Error = ValidationError;
 
     fn try_from(value: u64) -> Result<Self, Self::Error> {
         validate(value)?;
-        let pesel = Pesel(
-            ((crate::day_section(value) as u64) << DAY_SECTION_SHIFT as u64)
-                + ((crate::month_section(value) as u64) << MONTH_SECTION_SHIFT as u64)
-                + ((crate::year_section(value) as u64) << YEAR_SECTION_SHIFT as u64)
-                + ((crate::ordinal_section(value) as u64) << ORDINAL_SECTION_SHIFT as u64)
-                + ((crate::control_section(value) as u64) << CONTROL_SECTION_SHIFT as u64),
+        Ok(Self::new_unchecked(value))
+    }
+}
+
+impl TryFrom<&u64> for Pesel {
+    type Error = ValidationError;
+
+    fn try_from(value: &u64) -> Result<Self, Self::Error> {
+        Self::try_from(*value)
+    }
+}
+
+/// # Errors
+/// Returns [`ValidationError::NonNumeric`] if `value` is not a non-negative integer, otherwise
+/// the PESEL is validated as usual.
+impl TryFrom<f64> for Pesel {
+    type Error = ValidationError;
+
+    fn try_from(value: f64) -> Result<Self, Self::Error> {
+        Self::try_from(u64_from_f64(value)?)
+    }
+}
+
+impl From<Pesel> for [u8; 11] {
+    fn from(value: Pesel) -> Self {
+        (&value).into()
+    }
+}
+
+/// Digits of the PESEL, most significant first, read straight from the sections.
+impl From<&Pesel> for [u8; 11] {
+    fn from(value: &Pesel) -> Self {
+        let (year, month, day) = (
+            value.year_section(),
+            value.month_section(),
+            value.day_section(),
         );
-        Ok(pesel)
+        let ordinal = value.ordinal_section();
+
+        [
+            year / 10,
+            year % 10,
+            month / 10,
+            month % 10,
+            day / 10,
+            day % 10,
+            (ordinal / 1000) as u8,
+            (ordinal / 100 % 10) as u8,
+            (ordinal / 10 % 10) as u8,
+            (ordinal % 10) as u8,
+            value.control_section(),
+        ]
+    }
+}
+
+/// Create a PESEL from its digits, most significant first.
+///
+/// # Errors
+/// Returns [`ValidationError::NonNumeric`] if any of the values is not a digit, otherwise the
+/// PESEL is validated as usual.
+impl TryFrom<[u8; 11]> for Pesel {
+    type Error = ValidationError;
+
+    fn try_from(value: [u8; 11]) -> Result<Self, Self::Error> {
+        if value.iter().any(|digit| *digit > 9) {
+            return Err(ValidationError::NonNumeric);
+        }
+
+        Self::try_from(
+            value
+                .iter()
+                .fold(0u64, |acc, digit| acc * 10 + *digit as u64),
+        )
+    }
+}
+
+impl From<Pesel> for Decomposed {
+    fn from(value: Pesel) -> Self {
+        value.decompose()
     }
 }
 
 impl From<crate::human_redable::Pesel> for Pesel {
     fn from(value: crate::human_redable::Pesel) -> Self {
-        Self(
-            ((value.day_section() as u64) << DAY_SECTION_SHIFT as u64)
-                + ((value.month_section() as u64) << MONTH_SECTION_SHIFT as u64)
-                + ((value.year_section() as u64) << YEAR_SECTION_SHIFT as u64)
-                + ((value.ordinal_section() as u64) << ORDINAL_SECTION_SHIFT as u64)
-                + ((value.control_section() as u64) << CONTROL_SECTION_SHIFT as u64),
-        )
+        (&value).into()
     }
 }
 
-impl PeselTrait for Pesel {
+impl From<&crate::human_redable::Pesel> for Pesel {
+    fn from(value: &crate::human_redable::Pesel) -> Self {
+        debug_assert!((value.day_section() as u64) < 1 << DAY_SECTION_SIZE);
+        debug_assert!((value.month_section() as u64) < 1 << MONTH_SECTION_SIZE);
+        debug_assert!((value.year_section() as u64) < 1 << YEAR_SECTION_SIZE);
+        debug_assert!((value.ordinal_section() as u64) < 1 << ORDINAL_SECTION_SIZE);
+        debug_assert!((value.control_section() as u64) < 1 << CONTROL_SECTION_SIZE);
+
+        // The source is already a PESEL, only repack it without validating again.
+        Self::new_unchecked(value.value())
+    }
+}
+
+impl PeselTrait for Pesel {}
+
+impl PeselSections for Pesel {
     fn day_section(&self) -> u8 {
         (self.0 >> DAY_SECTION_SHIFT & (2u64.pow(DAY_SECTION_SIZE as u32) - 1)) as u8
     }
@@ -106,7 +264,24 @@ impl PeselTrait for Pesel {
     }
 }
 
+/// Equal when the person was born exactly on the given date.
+#[cfg(feature = "chrono")]
+impl PartialEq<NaiveDate> for Pesel {
+    fn eq(&self, other: &NaiveDate) -> bool {
+        self.date_of_birth() == *other
+    }
+}
+
+/// Compares the date of birth, `pesel < date` means the person was born before `date`.
+#[cfg(feature = "chrono")]
+impl PartialOrd<NaiveDate> for Pesel {
+    fn partial_cmp(&self, other: &NaiveDate) -> Option<std::cmp::Ordering> {
+        self.date_of_birth().partial_cmp(other)
+    }
+}
+
 #[cfg(test)]
+#[allow(clippy::zero_prefixed_literal)]
 mod tests {
     use super::*;
     use std::sync::LazyLock;
@@ -190,6 +365,7 @@ mod tests {
     }
 
     #[test]
+    #[cfg(feature = "chrono")]
     fn date_of_birth() {
         assert_eq!(
             PESEL1.date_of_birth(),
@@ -213,6 +389,39 @@ mod tests {
         );
     }
 
+    #[test]
+    #[cfg(feature = "chrono")]
+    fn checked_accessors() {
+        assert_eq!(PESEL1.checked_month(), Some(09));
+        assert_eq!(
+            PESEL1.checked_date_of_birth(),
+            NaiveDate::from_ymd_opt(2002, 09, 04)
+        );
+
+        let pesel = Pesel::new_unchecked(02130486168u64);
+        assert_eq!(pesel.checked_month(), None);
+        assert_eq!(pesel.checked_date_of_birth(), None);
+
+        let pesel = Pesel::new_unchecked(02293186168u64);
+        assert_eq!(pesel.checked_month(), Some(09));
+        assert_eq!(pesel.checked_date_of_birth(), None);
+
+        // Digits beyond the 11th don't fit the packed fields.
+        let pesel = Pesel::new_unchecked(102290486168u64);
+        assert_eq!(pesel, *PESEL1);
+        assert_eq!(pesel.checked_ordinal_section(), Some(8616));
+    }
+
+    #[test]
+    #[cfg(feature = "chrono")]
+    fn age_at() {
+        let on = |year, month, day| NaiveDate::from_ymd_opt(year, month, day).unwrap();
+
+        assert_eq!(PESEL1.age_at(on(2025, 09, 03)), 22);
+        assert_eq!(PESEL1.age_at(on(2025, 09, 04)), 23);
+        assert_eq!(PESEL5.age_at(on(1950, 01, 01)), 0);
+    }
+
     #[test]
     fn gender() {
         assert_eq!(PESEL1.gender(), Gender::Female);
@@ -222,6 +431,106 @@ mod tests {
         assert_eq!(PESEL5.gender(), Gender::Male);
     }
 
+    #[test]
+    fn with_digits() {
+        let checksum = |digits: &[u8; 11]| {
+            digits
+                .iter()
+                .zip(PESEL_WEIGHTS)
+                .map(|(digit, weight)| (digit * weight) as u16)
+                .sum::<u16>()
+        };
+
+        assert_eq!(PESEL1.with_digits(checksum) % 10, 0);
+        assert_eq!(PESEL3.with_digits(checksum) % 10, 0);
+        assert_eq!(PESEL3.with_digits(|digits| digits[3]), 1);
+    }
+
+    #[test]
+    fn decompose() {
+        assert_eq!(
+            Decomposed::from(PESEL1.to_owned()),
+            Decomposed {
+                year: 2002,
+                month: 09,
+                day: 04,
+                serial: 861,
+                gender: Gender::Female,
+                control: 8,
+            }
+        );
+        assert_eq!(PESEL2.serial_number(), 346);
+        assert_eq!(PESEL3.serial_number(), 285);
+    }
+
+    #[test]
+    fn normalize() {
+        assert_eq!(
+            PESEL1.to_owned().normalize(),
+            crate::human_redable::Pesel::try_from(02290486168).unwrap()
+        );
+        assert_eq!(PESEL3.to_owned().normalize().to_string(), "00010128545");
+    }
+
+    #[test]
+    fn debug() {
+        assert_eq!(
+            format!("{:?}", *PESEL1),
+            "Pesel { year: 2002, month: 9, day: 4, serial: 861, gender: Female }"
+        );
+        assert_eq!(
+            format!("{:?}", *PESEL5),
+            "Pesel { year: 1960, month: 3, day: 24, serial: 178, gender: Male }"
+        );
+    }
+
+    #[test]
+    fn human_redable_round_trip() {
+        use crate::human_redable::Pesel as HumanRedable;
+
+        for pesel in [
+            02290486168,
+            01302534699,
+            00010128545,
+            98250993285,
+            60032417874,
+            50923112348,
+            50523112346,
+            50723112342,
+        ] {
+            let human_redable = HumanRedable::try_from(pesel).unwrap();
+            let bit_fields = Pesel::from(&human_redable);
+            assert_eq!(bit_fields, Pesel::try_from(pesel).unwrap());
+            assert_eq!(u64::from(&bit_fields), pesel);
+            assert_eq!(HumanRedable::from(bit_fields), human_redable);
+        }
+    }
+
+    #[test]
+    fn hash_agrees_with_human_redable() {
+        use crate::human_redable::Pesel as HumanRedable;
+        use std::hash::{BuildHasher, RandomState};
+
+        let state = RandomState::new();
+        for pesel in [&*PESEL1, &*PESEL2, &*PESEL3, &*PESEL4, &*PESEL5] {
+            assert_eq!(
+                state.hash_one(pesel),
+                state.hash_one(HumanRedable::from(pesel))
+            );
+        }
+    }
+
+    #[test]
+    fn human_redable_conversion_does_not_validate() {
+        use crate::human_redable::Pesel as HumanRedable;
+
+        let human_redable = HumanRedable::new_unchecked(02290486167);
+        let bit_fields = Pesel::from(&human_redable);
+        assert_eq!(bit_fields, Pesel::new_unchecked(02290486167));
+        assert_eq!(HumanRedable::from(&bit_fields), human_redable);
+        assert_eq!(HumanRedable::from(bit_fields), human_redable);
+    }
+
     #[test]
     fn invalid_pesels() {
         assert_eq!(Pesel::try_from(4355), Err(ValidationError::TooShort(4)));
@@ -231,7 +540,7 @@ mod tests {
         );
         assert_eq!(
             Pesel::try_from(99990486167),
-            Err(ValidationError::BirthDate)
+            Err(ValidationError::InvalidMonth)
         );
         assert_eq!(
             Pesel::try_from(02290486167),
@@ -239,6 +548,38 @@ mod tests {
         );
     }
 
+    #[test]
+    fn try_from_u64_ref() {
+        let pesels: &[u64] = &[02290486168, 01302534699, 02290486167];
+        assert_eq!(
+            pesels.iter().map(Pesel::try_from).collect::<Vec<_>>(),
+            [
+                Ok(PESEL1.to_owned()),
+                Ok(PESEL2.to_owned()),
+                Err(ValidationError::ControlDigit)
+            ]
+        );
+    }
+
+    #[test]
+    fn try_from_f64() {
+        assert_eq!(Pesel::try_from(2290486168.0), Ok(PESEL1.to_owned()));
+        assert_eq!(Pesel::try_from(98250993285.0), Ok(PESEL4.to_owned()));
+        assert_eq!(
+            Pesel::try_from(2290486168.5),
+            Err(ValidationError::NonNumeric)
+        );
+        assert_eq!(
+            Pesel::try_from(-2290486168.0),
+            Err(ValidationError::NonNumeric)
+        );
+        assert_eq!(Pesel::try_from(f64::NAN), Err(ValidationError::NonNumeric));
+        assert_eq!(
+            Pesel::try_from(435585930294485.0),
+            Err(ValidationError::TooLong(15))
+        );
+    }
+
     #[test]
     fn try_from_strings() {
         assert_eq!(
@@ -263,6 +604,90 @@ mod tests {
         );
     }
 
+    #[test]
+    #[cfg(feature = "chrono")]
+    fn with_birth_date_keeping_serial() {
+        let date = NaiveDate::from_ymd_opt(1850, 12, 31).unwrap();
+        let pesel = PESEL2.with_birth_date_keeping_serial(date).unwrap();
+        assert_eq!(pesel.date_of_birth(), date);
+        assert_eq!(pesel.ordinal_section(), PESEL2.ordinal_section());
+        assert_eq!(pesel.gender(), Gender::Male);
+    }
+
+    #[test]
+    fn packed_bits() {
+        assert_eq!(BITS_USED, 57);
+        for pesel in [&*PESEL1, &*PESEL2, &*PESEL3, &*PESEL4, &*PESEL5] {
+            assert!(pesel.packed_bits() < 1 << BITS_USED);
+            assert_eq!(
+                Pesel::try_from(pesel.to_u64()).unwrap().packed_bits(),
+                pesel.packed_bits()
+            );
+        }
+    }
+
+    #[test]
+    fn digits() {
+        let digits = <[u8; 11]>::from(PESEL1.to_owned());
+        assert_eq!(digits, [0, 2, 2, 9, 0, 4, 8, 6, 1, 6, 8]);
+        assert_eq!(Pesel::try_from(digits), Ok(PESEL1.to_owned()));
+        assert_eq!(<[u8; 11]>::from(&*PESEL4), to_digits(&*PESEL4));
+
+        assert_eq!(
+            Pesel::try_from([0, 2, 2, 9, 0, 4, 8, 6, 1, 6, 7]),
+            Err(ValidationError::ControlDigit)
+        );
+        assert_eq!(
+            Pesel::try_from([0, 2, 2, 9, 0, 4, 8, 6, 1, 6, 10]),
+            Err(ValidationError::NonNumeric)
+        );
+    }
+
+    #[test]
+    fn to_u64() {
+        assert_eq!(
+            "02290486168".parse::<Pesel>().unwrap().to_u64(),
+            02290486168
+        );
+        assert_eq!(Pesel::try_from(98250993285).unwrap().to_u64(), 98250993285);
+    }
+
+    #[test]
+    fn validate_self() {
+        assert_eq!(PESEL1.validate_self(), Ok(()));
+        assert_eq!(
+            Pesel::new_unchecked(02290486167).validate_self(),
+            Err(ValidationError::ControlDigit)
+        );
+        assert_eq!(
+            Pesel::new_unchecked(02293186168).validate_self(),
+            Err(ValidationError::InvalidDay)
+        );
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(format!("{}", *PESEL3), "00010128545");
+        assert_eq!(format!("{:#x}", *PESEL3), "0x4010164c05");
+        assert_eq!(format!("{:x}", *PESEL3), "4010164c05");
+        assert_eq!(
+            format!("{:#x}", *PESEL1),
+            format!("{:#x}", PESEL1.packed_bits())
+        );
+    }
+
+    #[test]
+    fn from_str() {
+        let pesel = "00010128545".parse::<Pesel>().unwrap();
+        assert_eq!(pesel, PESEL3.to_owned());
+        assert_eq!(pesel.year_section(), 00);
+        assert_eq!(pesel.to_string(), "00010128545");
+
+        assert_eq!(PESEL1.to_string(), "02290486168");
+        assert_eq!(PESEL4.to_string(), "98250993285");
+        "02290486167".parse::<Pesel>().expect_err("Invalid PESEL");
+    }
+
     #[test]
     #[cfg(feature = "serde")]
     fn deserialize() {
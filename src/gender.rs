@@ -0,0 +1,136 @@
+//! Gender encoded in the last digit of the ordinal section.
+//!
+//! ```rust
+//! use pesel_rs::gender::{self, Gender};
+//!
+//! assert_eq!(gender::from_parity(6), Gender::Female);
+//! assert_eq!(gender::from_parity(1), Gender::Male);
+//! assert_eq!(gender::to_digit_example(Gender::Male), 1);
+//! assert_eq!(Gender::Female.opposite(), Gender::Male);
+//! assert_eq!(Gender::Male.as_str(), "male");
+//! assert_eq!("K".parse(), Ok(Gender::Female));
+//! ```
+
+use std::str::FromStr;
+use thiserror::Error;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Gender {
+    Male,
+    Female,
+}
+
+/// Ordered with [`Gender::Female`] before [`Gender::Male`], matching the parity of the digits.
+impl Ord for Gender {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        to_digit_example(self.clone()).cmp(&to_digit_example(other.clone()))
+    }
+}
+
+impl PartialOrd for Gender {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Gender {
+    /// The other gender.
+    pub const fn opposite(&self) -> Self {
+        match self {
+            Self::Male => Self::Female,
+            Self::Female => Self::Male,
+        }
+    }
+
+    /// Lowercase name of the gender, `"male"` or `"female"`.
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::Male => "male",
+            Self::Female => "female",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+#[error("Invalid gender, expected \"M\", \"K\", \"Male\" or \"Female\".")]
+pub struct GenderParseError;
+
+/// Parses `"M"`, `"Male"`, `"K"` (kobieta) or `"Female"`, ignoring case.
+impl FromStr for Gender {
+    type Err = GenderParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if ["m", "male"]
+            .iter()
+            .any(|name| s.eq_ignore_ascii_case(name))
+        {
+            Ok(Self::Male)
+        } else if ["k", "female"]
+            .iter()
+            .any(|name| s.eq_ignore_ascii_case(name))
+        {
+            Ok(Self::Female)
+        } else {
+            Err(GenderParseError)
+        }
+    }
+}
+
+/// `true` for [`Gender::Male`] and `false` for [`Gender::Female`], for compact storage.
+impl From<Gender> for bool {
+    fn from(value: Gender) -> Self {
+        value == Gender::Male
+    }
+}
+
+/// Reverse of `bool::from`, `true` being [`Gender::Male`].
+impl From<bool> for Gender {
+    fn from(value: bool) -> Self {
+        if value {
+            Self::Male
+        } else {
+            Self::Female
+        }
+    }
+}
+
+/// `'M'` for [`Gender::Male`] and `'K'` (kobieta) for [`Gender::Female`].
+impl From<Gender> for char {
+    fn from(value: Gender) -> Self {
+        match value {
+            Gender::Male => 'M',
+            Gender::Female => 'K',
+        }
+    }
+}
+
+/// Parses `'M'` or `'K'`, ignoring case.
+impl TryFrom<char> for Gender {
+    type Error = GenderParseError;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value.to_ascii_uppercase() {
+            'M' => Ok(Self::Male),
+            'K' => Ok(Self::Female),
+            _ => Err(GenderParseError),
+        }
+    }
+}
+
+/// Gender denoted by a digit (or the whole ordinal section), even being female and odd male.
+pub const fn from_parity(digit: u16) -> Gender {
+    if digit.is_multiple_of(2) {
+        Gender::Female
+    } else {
+        Gender::Male
+    }
+}
+
+/// The lowest digit denoting `gender`, `0` for female and `1` for male.
+pub const fn to_digit_example(gender: Gender) -> u8 {
+    match gender {
+        Gender::Female => 0,
+        Gender::Male => 1,
+    }
+}
@@ -1,17 +1,434 @@
 use super::*;
+#[cfg(feature = "chrono")]
+use chrono::Datelike;
 
 /// Stores the PESEL as is, in human redable form.
 ///
 /// Used when frequently reading the human redable representation without accessing the individual
 /// fields.
-#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
-#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+///
+/// Layout compatible with [`u64`], so a pointer to it can be passed where a pointer to the human
+/// readable number is expected, e.g. over FFI.
+#[repr(transparent)]
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(
+    feature = "diesel",
+    derive(diesel::expression::AsExpression, diesel::deserialize::FromSqlRow)
+)]
+#[cfg_attr(feature = "diesel", diesel(sql_type = diesel::sql_types::Text))]
 pub struct Pesel(u64);
 
+const _: () = assert!(std::mem::size_of::<Pesel>() == std::mem::size_of::<u64>());
+const _: () = assert!(std::mem::align_of::<Pesel>() == std::mem::align_of::<u64>());
+
+impl Pesel {
+    /// Create a PESEL from a human readable number without validating it.
+    ///
+    /// Accessors such as [`PeselTrait::date_of_birth`] may panic if `value` isn't a valid PESEL.
+    /// The `checked_*` accessors, [`PeselTrait::validate_self`] and
+    /// [`PeselTrait::control_matches`] probe the contents without panicking, for a valid PESEL
+    /// they never report a problem.
+    pub const fn new_unchecked(value: u64) -> Self {
+        Self(value)
+    }
+
+    /// Human readable number of the PESEL, same as `u64::from`.
+    pub const fn value(&self) -> u64 {
+        self.0
+    }
+
+    /// Create a PESEL from a date of birth and a full ordinal section, computing the month
+    /// section and the control digit.
+    ///
+    /// The last digit of `serial` denotes the gender, as it does in the ordinal section.
+    ///
+    /// # Errors
+    /// - [`ValidationError::InvalidMonth`] if `date` is not between `1800-01-01` and `2299-12-31`,
+    ///   as the month section can't encode its year
+    /// - [`ValidationError::Ordinal`] if `serial` is above `9999`
+    #[cfg(feature = "chrono")]
+    pub fn try_from_date_and_serial(date: NaiveDate, serial: u16) -> Result<Self, ValidationError> {
+        if serial > 9999 {
+            return Err(ValidationError::Ordinal);
+        }
+
+        let year = u16::try_from(date.year()).map_err(|_| ValidationError::InvalidMonth)?;
+        let pesel = assemble(year, date.month() as u8, date.day() as u8, serial)?;
+        Self::try_from(pesel)
+    }
+
+    /// Create a PESEL from a number and the count of digits its source had, including the
+    /// leading zeros lost when it was turned into a number.
+    ///
+    /// Making the caller supply the count rejects e.g. a 9 digit typo that would otherwise be
+    /// taken for a PESEL with leading zeros, see [`crate::validate_known_length`].
+    ///
+    /// # Errors
+    /// - [`ValidationError::TooShort`] or [`ValidationError::TooLong`] if `digits` is not `11`
+    /// - [`ValidationError::TooLong`] if `value` has more than `digits` digits
+    /// - otherwise the PESEL is validated as usual
+    pub fn from_numeric_unchecked_leading_zeros(
+        (value, digits): (u64, usize),
+    ) -> Result<Self, ValidationError> {
+        let len = value.checked_ilog10().map_or(1, |log| log as usize + 1);
+        if len > digits {
+            return Err(ValidationError::TooLong(len));
+        }
+
+        match digits {
+            ..11 => Err(ValidationError::TooShort(digits)),
+            11 => Self::try_from(value),
+            _ => Err(ValidationError::TooLong(digits)),
+        }
+    }
+
+    /// Copy of the PESEL with a different date of birth, keeping the whole ordinal section.
+    ///
+    /// The serial number and the gender are guaranteed to be preserved, only the date sections
+    /// and the control digit are recomputed.
+    ///
+    /// # Errors
+    /// Same as [`Pesel::try_from_date_and_serial`].
+    #[cfg(feature = "chrono")]
+    pub fn with_birth_date_keeping_serial(&self, date: NaiveDate) -> Result<Self, ValidationError> {
+        Self::try_from_date_and_serial(date, self.ordinal_section())
+    }
+
+    /// Copy of the PESEL with a random ordinal section, keeping the date of birth and the gender.
+    ///
+    /// The control digit is recomputed, so the result is always valid. Useful for generating
+    /// k-anonymous variants of a record, note that the result may equal `self`.
+    #[cfg(feature = "rand")]
+    pub fn scramble_serial<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> Self {
+        let gender_digit = self.ordinal_section() % 2;
+        loop {
+            let ordinal = rng.gen_range(0..5000) * 2 + gender_digit;
+            // Fails only for single repeated digit PESELs, e.g. `22222222222`.
+            if let Ok(pesel) = Self::try_from_date_and_serial(self.date_of_birth(), ordinal) {
+                return pesel;
+            }
+        }
+    }
+
+    /// Create a PESEL from a [`BirthDate`], a serial number and a gender, without going through
+    /// `chrono`.
+    ///
+    /// The gender digit is the lowest one matching `gender`, `0` for female and `1` for male.
+    ///
+    /// # Errors
+    /// - [`ValidationError::InvalidMonth`] if the month is not in range of `<1,12>` or the year
+    ///   is not in range of `<1800,2299>`
+    /// - [`ValidationError::InvalidDay`] if the day is out of range of the month
+    /// - [`ValidationError::Ordinal`] if `serial` is above `999`
+    pub fn try_from_birthdate(
+        date: BirthDate,
+        serial: u16,
+        gender: Gender,
+    ) -> Result<Self, ValidationError> {
+        if serial >= SERIALS_PER_DATE {
+            return Err(ValidationError::Ordinal);
+        }
+
+        let ordinal = serial * 10 + gender::to_digit_example(gender) as u16;
+        let pesel = assemble(date.year, date.month, date.day, ordinal)?;
+        Self::try_from(pesel)
+    }
+
+    /// Create a PESEL from an OS string, such as a command line argument.
+    ///
+    /// # Errors
+    /// Returns [`ValidationError::NonNumeric`] if `s` is not valid UTF-8 or contains characters
+    /// other than digits, otherwise the PESEL is validated as usual.
+    pub fn from_os_str(s: &std::ffi::OsStr) -> Result<Self, ValidationError> {
+        Self::parse(s.to_str().ok_or(ValidationError::NonNumeric)?)
+    }
+
+    /// Create a PESEL from a string as found in real-world files, e.g. CSV exports.
+    ///
+    /// Strips a leading UTF-8 BOM, surrounding whitespace and surrounding single or double quotes
+    /// before validating.
+    ///
+    /// # Errors
+    /// Returns [`ValidationError::NonNumeric`] if anything other than digits remains after
+    /// stripping, otherwise the PESEL is validated as usual.
+    pub fn parse_lenient(s: &str) -> Result<Self, ValidationError> {
+        let s = s.trim_start_matches('\u{feff}').trim();
+        let s = ['"', '\'']
+            .into_iter()
+            .find_map(|quote| s.strip_prefix(quote)?.strip_suffix(quote))
+            .unwrap_or(s);
+
+        Self::parse(s.trim())
+    }
+
+    /// Create a PESEL from a string mangled by a spreadsheet, such as `"2.290486168E+09"` or
+    /// `"2290486168"` with the leading zeros stripped.
+    ///
+    /// Numbers in scientific notation are reconstructed exactly, without going through floats.
+    ///
+    /// # Errors
+    /// Returns [`ValidationError::NonNumeric`] if `s` is not a number, has non-zero fractional
+    /// digits or an exponent out of range, otherwise the PESEL is validated as usual.
+    pub fn parse_spreadsheet(s: &str) -> Result<Self, ValidationError> {
+        let s = s.trim();
+        let (mantissa, exponent) = match s.split_once(['e', 'E']) {
+            Some((mantissa, exponent)) => (
+                mantissa,
+                exponent
+                    .parse::<i32>()
+                    .map_err(|_| ValidationError::NonNumeric)?,
+            ),
+            None => (s, 0),
+        };
+        let (integer, fraction) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+        if !integer
+            .bytes()
+            .chain(fraction.bytes())
+            .all(|byte| byte.is_ascii_digit())
+        {
+            return Err(ValidationError::NonNumeric);
+        }
+
+        let point = i32::try_from(integer.len())
+            .ok()
+            .and_then(|len| len.checked_add(exponent))
+            .and_then(|point| usize::try_from(point).ok())
+            .ok_or(ValidationError::NonNumeric)?;
+        if point > 20 {
+            return Err(ValidationError::TooLong(point));
+        }
+
+        let mut digits = format!("{integer}{fraction}");
+        if point < digits.len() {
+            if digits[point..].bytes().any(|byte| byte != b'0') {
+                return Err(ValidationError::NonNumeric);
+            }
+            digits.truncate(point);
+        } else {
+            digits.extend(std::iter::repeat_n('0', point - digits.len()));
+        }
+
+        Self::parse(&digits)
+    }
+
+    pub(crate) fn parse(s: &str) -> Result<Self, ValidationError> {
+        Self::try_from(s).map_err(|err| match err {
+            PeselTryFromError::ValidationError(err) => err,
+            PeselTryFromError::Other(err) => match err.kind() {
+                std::num::IntErrorKind::Empty => ValidationError::TooShort(0),
+                std::num::IntErrorKind::PosOverflow => ValidationError::TooLong(s.len()),
+                _ => ValidationError::NonNumeric,
+            },
+        })
+    }
+}
+
 impl_try_from_str_for_pesel!(Pesel);
 
+/// Serializes as an 11 character string, keeping the leading zeros.
+///
+/// Earlier versions serialized a number, which isn't accepted back if it's shorter than 11
+/// digits. [`crate::bit_fields::Pesel`] still serializes as a number.
+#[cfg(feature = "serde")]
+impl serde::Serialize for Pesel {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+// Numbers with fewer than 11 digits can't tell which leading zeros were lost, strings are always
+// preferred.
+#[cfg(feature = "serde")]
+impl_pesel_deserializer!(Pesel, min_digits = 11);
+
+/// Opt-in lenient deserialization, for use with `#[serde(deserialize_with = "...")]`.
+///
+/// Strings are cleaned up with [`Pesel::parse_lenient`] before validating, numbers are handled as
+/// by the default `Deserialize`. Invalid PESELs are rejected either way.
+///
+/// ```rust
+/// use pesel_rs::human_redable::{self, Pesel};
+///
+/// #[derive(serde::Deserialize)]
+/// struct Person {
+///     #[serde(deserialize_with = "human_redable::deserialize_lenient")]
+///     pesel: Pesel,
+/// }
+///
+/// let person: Person = serde_json::from_str(r#"{ "pesel": " '02290486168' " }"#).unwrap();
+/// assert_eq!(person.pesel.to_string(), "02290486168");
+/// ```
+#[cfg(feature = "serde")]
+pub fn deserialize_lenient<'de, D>(deserializer: D) -> Result<Pesel, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    struct LenientVisitor;
+
+    impl<'de> serde::de::Visitor<'de> for LenientVisitor {
+        type Value = Pesel;
+
+        fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+            write!(formatter, "a valid PESEL as u64 or a string")
+        }
+
+        fn visit_u64<E>(self, v: u64) -> Result<Self::Value, E>
+        where
+            E: serde::de::Error,
+        {
+            PeselVisitor.visit_u64(v)
+        }
+
+        fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+        where
+            E: serde::de::Error,
+        {
+            Pesel::parse_lenient(v).map_err(|err| serde::de::Error::custom(err.to_string()))
+        }
+    }
+
+    deserializer.deserialize_any(LenientVisitor)
+}
+
+/// Serializes the PESEL as a string together with its details, e.g.
+/// `{ "pesel": "00010128545", "year": 1900, "month": 1, "day": 1, "gender": "Female" }`.
+///
+/// Deserializing validates the `pesel` field, the details are optional but must be consistent
+/// with it when present.
+#[cfg(feature = "serde")]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PeselJson(pub Pesel);
+
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+struct PeselJsonFields {
+    pesel: String,
+    #[serde(default)]
+    year: Option<u16>,
+    #[serde(default)]
+    month: Option<u8>,
+    #[serde(default)]
+    day: Option<u8>,
+    #[serde(default)]
+    gender: Option<Gender>,
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for PeselJson {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        PeselJsonFields {
+            pesel: self.0.to_string(),
+            year: Some(self.0.year()),
+            month: Some(self.0.month()),
+            day: Some(self.0.day()),
+            gender: Some(self.0.gender()),
+        }
+        .serialize(serializer)
+    }
+}
+
 #[cfg(feature = "serde")]
-impl_pesel_deserializer!(Pesel);
+impl<'de> serde::Deserialize<'de> for PeselJson {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        use serde::de::Error;
+
+        let fields = PeselJsonFields::deserialize(deserializer)?;
+        let pesel = Pesel::try_from(fields.pesel.as_str()).map_err(D::Error::custom)?;
+
+        let consistent = fields.year.is_none_or(|year| year == pesel.year())
+            && fields.month.is_none_or(|month| month == pesel.month())
+            && fields.day.is_none_or(|day| day == pesel.day())
+            && fields.gender.is_none_or(|gender| gender == pesel.gender());
+        if !consistent {
+            return Err(D::Error::custom(
+                "Pesel details are inconsistent with the number.",
+            ));
+        }
+
+        Ok(Self(pesel))
+    }
+}
+
+#[cfg(feature = "sqlx")]
+impl<DB: sqlx::Database> sqlx::Type<DB> for Pesel
+where
+    String: sqlx::Type<DB>,
+{
+    fn type_info() -> DB::TypeInfo {
+        <String as sqlx::Type<DB>>::type_info()
+    }
+
+    fn compatible(ty: &DB::TypeInfo) -> bool {
+        <String as sqlx::Type<DB>>::compatible(ty)
+    }
+}
+
+/// Encodes the PESEL as an 11 character string.
+#[cfg(feature = "sqlx")]
+impl<'q, DB: sqlx::Database> sqlx::Encode<'q, DB> for Pesel
+where
+    String: sqlx::Encode<'q, DB>,
+{
+    fn encode_by_ref(
+        &self,
+        buf: &mut <DB as sqlx::Database>::ArgumentBuffer<'q>,
+    ) -> Result<sqlx::encode::IsNull, sqlx::error::BoxDynError> {
+        self.to_string().encode(buf)
+    }
+}
+
+/// Decodes the PESEL from a string, failing if it's not a valid PESEL.
+#[cfg(feature = "sqlx")]
+impl<'r, DB: sqlx::Database> sqlx::Decode<'r, DB> for Pesel
+where
+    &'r str: sqlx::Decode<'r, DB>,
+{
+    fn decode(
+        value: <DB as sqlx::Database>::ValueRef<'r>,
+    ) -> Result<Self, sqlx::error::BoxDynError> {
+        Ok(Self::try_from(<&str as sqlx::Decode<DB>>::decode(value)?)?)
+    }
+}
+
+/// Writes the PESEL as an 11 character string.
+#[cfg(feature = "diesel")]
+impl<DB> diesel::serialize::ToSql<diesel::sql_types::Text, DB> for Pesel
+where
+    for<'c> DB: diesel::backend::Backend<
+        BindCollector<'c> = diesel::query_builder::bind_collector::RawBytesBindCollector<DB>,
+    >,
+{
+    fn to_sql<'b>(
+        &'b self,
+        out: &mut diesel::serialize::Output<'b, '_, DB>,
+    ) -> diesel::serialize::Result {
+        use std::io::Write;
+
+        write!(out, "{self}")?;
+        Ok(diesel::serialize::IsNull::No)
+    }
+}
+
+/// Reads the PESEL from a string, failing if it's not a valid PESEL.
+#[cfg(feature = "diesel")]
+impl<DB> diesel::deserialize::FromSql<diesel::sql_types::Text, DB> for Pesel
+where
+    DB: diesel::backend::Backend,
+    String: diesel::deserialize::FromSql<diesel::sql_types::Text, DB>,
+{
+    fn from_sql(bytes: DB::RawValue<'_>) -> diesel::deserialize::Result<Self> {
+        Ok(Self::try_from(String::from_sql(bytes)?)?)
+    }
+}
 
 impl From<Pesel> for u64 {
     fn from(value: Pesel) -> Self {
@@ -25,22 +442,79 @@ impl From<&Pesel> for u64 {
     }
 }
 
+/// Formats the PESEL as `Pesel(02290486168)`.
+impl std::fmt::Debug for Pesel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("Pesel")
+            .field(&format_args!("{self}"))
+            .finish()
+    }
+}
+
+/// Formats the PESEL as an 11 digit number, keeping the leading zeros.
+impl std::fmt::Display for Pesel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:011}", self.0)
+    }
+}
+
 impl TryFrom<u64> for Pesel {
     type Error = ValidationError;
 
     fn try_from(value: u64) -> Result<Self, Self::Error> {
         validate(value)?;
-        Ok(Self(value))
+        Ok(Self::new_unchecked(value))
+    }
+}
+
+impl TryFrom<&u64> for Pesel {
+    type Error = ValidationError;
+
+    fn try_from(value: &u64) -> Result<Self, Self::Error> {
+        Self::try_from(*value)
+    }
+}
+
+/// # Errors
+/// Returns [`ValidationError::NonNumeric`] if `value` is not a non-negative integer, otherwise
+/// the PESEL is validated as usual.
+impl TryFrom<f64> for Pesel {
+    type Error = ValidationError;
+
+    fn try_from(value: f64) -> Result<Self, Self::Error> {
+        Self::try_from(u64_from_f64(value)?)
+    }
+}
+
+impl From<Pesel> for Decomposed {
+    fn from(value: Pesel) -> Self {
+        value.decompose()
+    }
+}
+
+/// Compares the [`PeselTrait::decompose`] view, note that it doesn't hold the exact gender digit.
+impl PartialEq<Decomposed> for Pesel {
+    fn eq(&self, other: &Decomposed) -> bool {
+        self.decompose() == *other
     }
 }
 
 impl From<crate::bit_fields::Pesel> for Pesel {
     fn from(value: crate::bit_fields::Pesel) -> Self {
+        (&value).into()
+    }
+}
+
+impl From<&crate::bit_fields::Pesel> for Pesel {
+    fn from(value: &crate::bit_fields::Pesel) -> Self {
+        // The source is already a PESEL, only unpack it without validating again.
         Self(u64::from(value))
     }
 }
 
-impl PeselTrait for Pesel {
+impl PeselTrait for Pesel {}
+
+impl PeselSections for Pesel {
     fn day_section(&self) -> u8 {
         day_section(self)
     }
@@ -62,13 +536,45 @@ impl PeselTrait for Pesel {
     }
 }
 
+#[cfg(feature = "chrono")]
+impl TryFrom<&Pesel> for NaiveDate {
+    type Error = ValidationError;
+
+    fn try_from(value: &Pesel) -> Result<Self, Self::Error> {
+        checked_date_of_birth(value.into())
+    }
+}
+
+/// Equal when the person was born exactly on the given date.
+#[cfg(feature = "chrono")]
+impl PartialEq<NaiveDate> for Pesel {
+    fn eq(&self, other: &NaiveDate) -> bool {
+        self.date_of_birth() == *other
+    }
+}
+
+/// Compares the date of birth, `pesel < date` means the person was born before `date`.
+#[cfg(feature = "chrono")]
+impl PartialOrd<NaiveDate> for Pesel {
+    fn partial_cmp(&self, other: &NaiveDate) -> Option<std::cmp::Ordering> {
+        self.date_of_birth().partial_cmp(other)
+    }
+}
+
 impl AsRef<u64> for Pesel {
     fn as_ref(&self) -> &u64 {
         &self.0
     }
 }
 
+impl std::borrow::Borrow<u64> for Pesel {
+    fn borrow(&self) -> &u64 {
+        &self.0
+    }
+}
+
 #[cfg(test)]
+#[allow(clippy::zero_prefixed_literal)]
 mod tests {
     use super::*;
     use std::sync::LazyLock;
@@ -152,6 +658,7 @@ mod tests {
     }
 
     #[test]
+    #[cfg(feature = "chrono")]
     fn date_of_birth() {
         assert_eq!(
             PESEL1.date_of_birth(),
@@ -175,6 +682,110 @@ mod tests {
         );
     }
 
+    #[test]
+    fn birth_date_iso() {
+        assert_eq!(PESEL3.birth_date_iso(), "1900-01-01");
+        assert_eq!(PESEL1.birth_date_iso(), "2002-09-04");
+    }
+
+    #[test]
+    #[cfg(feature = "chrono")]
+    fn weekday_of_birth() {
+        assert_eq!(PESEL1.weekday_of_birth(), chrono::Weekday::Wed);
+        assert_eq!(PESEL3.weekday_of_birth(), chrono::Weekday::Mon);
+    }
+
+    #[test]
+    #[cfg(feature = "rand")]
+    fn scramble_serial() {
+        use rand::SeedableRng;
+
+        let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(2137);
+        for pesel in [&*PESEL1, &*PESEL2, &*PESEL3, &*PESEL4, &*PESEL5] {
+            let mut changed = 0;
+            for _ in 0..100 {
+                let scrambled = pesel.scramble_serial(&mut rng);
+                assert_eq!(validate(&scrambled), Ok(()));
+                assert_eq!(scrambled.date_of_birth(), pesel.date_of_birth());
+                assert_eq!(scrambled.gender(), pesel.gender());
+                if scrambled.serial_number() != pesel.serial_number() {
+                    changed += 1;
+                }
+            }
+            assert!(changed > 90);
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "chrono")]
+    fn born_on_weekend() {
+        assert!(!PESEL1.born_on_weekend());
+        assert!(!PESEL4.born_on_weekend());
+
+        let saturday = NaiveDate::from_ymd_opt(2002, 09, 07).unwrap();
+        let sunday = NaiveDate::from_ymd_opt(2002, 09, 08).unwrap();
+        for date in [saturday, sunday] {
+            let pesel = Pesel::try_from_date_and_serial(date, 861).unwrap();
+            assert!(pesel.born_on_weekend());
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "chrono")]
+    fn try_into_date() {
+        assert_eq!(
+            NaiveDate::try_from(&*PESEL1),
+            Ok(NaiveDate::from_ymd_opt(2002, 09, 04).unwrap())
+        );
+        assert_eq!(
+            NaiveDate::try_from(&Pesel::new_unchecked(02223086160)),
+            Err(ValidationError::InvalidDay)
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "chrono")]
+    fn checked_accessors() {
+        assert_eq!(PESEL1.checked_month(), Some(09));
+        assert_eq!(
+            PESEL1.checked_date_of_birth(),
+            NaiveDate::from_ymd_opt(2002, 09, 04)
+        );
+
+        let pesel = Pesel::new_unchecked(02330486168u64);
+        assert_eq!(pesel.checked_month(), None);
+        assert_eq!(pesel.checked_date_of_birth(), None);
+
+        let pesel = Pesel::new_unchecked(02293186168u64);
+        assert_eq!(pesel.checked_month(), Some(09));
+        assert_eq!(pesel.checked_date_of_birth(), None);
+
+        assert_eq!(PESEL1.checked_ordinal_section(), Some(8616));
+        assert_eq!(PESEL1.checked_serial_number(), Some(861));
+
+        let pesel = Pesel::new_unchecked(102290486168u64);
+        assert_eq!(pesel.checked_ordinal_section(), None);
+        assert_eq!(pesel.checked_serial_number(), None);
+    }
+
+    #[test]
+    #[cfg(feature = "chrono")]
+    fn age_at() {
+        let on = |year, month, day| NaiveDate::from_ymd_opt(year, month, day).unwrap();
+
+        assert_eq!(PESEL1.age_at(on(2025, 01, 01)), 22);
+        assert_eq!(PESEL1.age_at(on(2025, 09, 03)), 22);
+        assert_eq!(PESEL1.age_at(on(2025, 09, 04)), 23);
+        assert_eq!(PESEL1.age_at(on(2002, 09, 04)), 0);
+        assert_eq!(PESEL1.age_at(on(1990, 01, 01)), 0);
+
+        let last = Pesel::try_from_date_and_serial(on(2299, 12, 31), 0).unwrap();
+        assert_eq!(last.age_at(on(2025, 01, 01)), 0);
+
+        let first = Pesel::try_from_date_and_serial(on(1800, 01, 01), 0).unwrap();
+        assert_eq!(first.age_at(on(2299, 12, 31)), 499);
+    }
+
     #[test]
     fn gender() {
         assert_eq!(PESEL1.gender(), Gender::Female);
@@ -184,6 +795,384 @@ mod tests {
         assert_eq!(PESEL5.gender(), Gender::Male);
     }
 
+    #[test]
+    fn borrow_as_map_key() {
+        use std::collections::HashMap;
+
+        let mut map = HashMap::new();
+        map.insert(PESEL1.to_owned(), "PESEL1");
+        map.insert(PESEL2.to_owned(), "PESEL2");
+
+        assert_eq!(map.get(&02290486168u64), Some(&"PESEL1"));
+        assert_eq!(map.get(&01302534699u64), Some(&"PESEL2"));
+        assert_eq!(map.get(&00010128545u64), None);
+    }
+
+    #[test]
+    fn with_digits() {
+        let checksum = |digits: &[u8; 11]| {
+            digits
+                .iter()
+                .zip(PESEL_WEIGHTS)
+                .map(|(digit, weight)| (digit * weight) as u16)
+                .sum::<u16>()
+        };
+
+        assert_eq!(PESEL1.with_digits(checksum) % 10, 0);
+        assert_eq!(PESEL3.with_digits(checksum) % 10, 0);
+        assert_eq!(PESEL3.with_digits(|digits| digits[3]), 1);
+    }
+
+    #[test]
+    #[cfg(feature = "chrono")]
+    fn birth_date_parts() {
+        use chrono::Datelike;
+
+        for pesel in [&*PESEL1, &*PESEL2, &*PESEL3, &*PESEL4, &*PESEL5] {
+            let date_of_birth = pesel.date_of_birth();
+            assert_eq!(
+                pesel.birth_date_parts(),
+                BirthDate {
+                    year: date_of_birth.year() as u16,
+                    month: date_of_birth.month() as u8,
+                    day: date_of_birth.day() as u8,
+                }
+            );
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "chrono")]
+    fn is_plausible_registration() {
+        let on = NaiveDate::from_ymd_opt(2026, 10, 14).unwrap();
+        assert!(PESEL3.is_plausible_registration(on));
+        assert!(PESEL1.is_plausible_registration(on));
+        assert!(!PESEL4.is_plausible_registration(on));
+        assert!(!Pesel::try_from(50923112348)
+            .unwrap()
+            .is_plausible_registration(on));
+    }
+
+    #[test]
+    #[cfg(feature = "time")]
+    fn birth_date_time() {
+        let date = PESEL1.birth_date_time();
+        assert_eq!(
+            (date.year(), date.month(), date.day()),
+            (2002, time::Month::September, 04)
+        );
+
+        let date = PESEL3.birth_date_time();
+        assert_eq!(
+            (date.year(), date.month(), date.day()),
+            (1900, time::Month::January, 01)
+        );
+    }
+
+    #[test]
+    fn control() {
+        for pesel in [&*PESEL1, &*PESEL2, &*PESEL3, &*PESEL4, &*PESEL5] {
+            assert_eq!(pesel.stored_control(), pesel.expected_control());
+            assert!(pesel.control_matches());
+        }
+
+        let pesel = Pesel::new_unchecked(02290486167);
+        assert_eq!(pesel.stored_control(), 7);
+        assert_eq!(pesel.expected_control(), 8);
+        assert!(!pesel.control_matches());
+    }
+
+    #[test]
+    fn decompose() {
+        assert_eq!(
+            Decomposed::from(PESEL1.to_owned()),
+            Decomposed {
+                year: 2002,
+                month: 09,
+                day: 04,
+                serial: 861,
+                gender: Gender::Female,
+                control: 8,
+            }
+        );
+        assert_eq!(PESEL2.serial_number(), 346);
+        assert_eq!(PESEL3.serial_number(), 285);
+    }
+
+    #[test]
+    fn eq_decomposed() {
+        let decomposed = Decomposed {
+            year: 2002,
+            month: 09,
+            day: 04,
+            serial: 861,
+            gender: Gender::Female,
+            control: 8,
+        };
+        assert!(*PESEL1 == decomposed);
+        assert!(*PESEL2 != decomposed);
+    }
+
+    #[test]
+    fn into_parts() {
+        let (year, month, day, serial, gender, control) = PESEL2.to_owned().into_parts();
+        assert_eq!(
+            (year, month, day, serial, gender, control),
+            (2001, 10, 25, 346, Gender::Male, 9)
+        );
+    }
+
+    #[test]
+    fn hash_agrees_with_bit_fields() {
+        use std::hash::{BuildHasher, RandomState};
+
+        let state = RandomState::new();
+        for pesel in [&*PESEL1, &*PESEL2, &*PESEL3, &*PESEL4, &*PESEL5] {
+            assert_eq!(
+                state.hash_one(pesel),
+                state.hash_one(crate::bit_fields::Pesel::from(pesel))
+            );
+        }
+    }
+
+    #[test]
+    fn normalize() {
+        assert_eq!(PESEL1.to_owned().normalize(), PESEL1.to_owned());
+        assert_eq!(PESEL3.to_owned().normalize().to_string(), "00010128545");
+    }
+
+    #[test]
+    #[cfg(feature = "chrono")]
+    fn remaining_serials() {
+        let date = NaiveDate::from_ymd_opt(2002, 09, 04).unwrap();
+        let used: Vec<Pesel> = [8616, 8617, 1230, 4567]
+            .into_iter()
+            .map(|ordinal| Pesel::try_from_date_and_serial(date, ordinal).unwrap())
+            .collect();
+
+        assert_eq!(Pesel::remaining_serials(&[]), SERIALS_PER_DATE);
+        assert_eq!(Pesel::remaining_serials(&used), 997);
+    }
+
+    #[test]
+    fn debug() {
+        assert_eq!(format!("{:?}", *PESEL1), "Pesel(02290486168)");
+        assert_eq!(format!("{:?}", *PESEL3), "Pesel(00010128545)");
+    }
+
+    #[test]
+    fn from_numeric_unchecked_leading_zeros() {
+        assert_eq!(
+            Pesel::from_numeric_unchecked_leading_zeros((10128545, 11)),
+            Ok(PESEL3.to_owned())
+        );
+        assert_eq!(
+            Pesel::from_numeric_unchecked_leading_zeros((10128545, 8)),
+            Err(ValidationError::TooShort(8))
+        );
+        assert_eq!(
+            Pesel::from_numeric_unchecked_leading_zeros((10128545, 12)),
+            Err(ValidationError::TooLong(12))
+        );
+        assert_eq!(
+            Pesel::from_numeric_unchecked_leading_zeros((98250993285, 10)),
+            Err(ValidationError::TooLong(11))
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "chrono")]
+    fn with_birth_date_keeping_serial() {
+        let date = NaiveDate::from_ymd_opt(2150, 02, 28).unwrap();
+        let pesel = PESEL2.with_birth_date_keeping_serial(date).unwrap();
+        assert_eq!(pesel.date_of_birth(), date);
+        assert_eq!(pesel.gender(), PESEL2.gender());
+        assert_eq!(pesel.serial_number(), PESEL2.serial_number());
+        assert_eq!(pesel.ordinal_section(), PESEL2.ordinal_section());
+
+        let date = NaiveDate::from_ymd_opt(1799, 12, 31).unwrap();
+        assert_eq!(
+            PESEL2.with_birth_date_keeping_serial(date),
+            Err(ValidationError::InvalidMonth)
+        );
+    }
+
+    #[test]
+    fn try_from_birthdate() {
+        let date = BirthDate {
+            year: 2002,
+            month: 9,
+            day: 4,
+        };
+        assert_eq!(
+            Pesel::try_from_birthdate(date, 861, Gender::Female),
+            Pesel::try_from(02290486106)
+        );
+        assert_eq!(
+            Pesel::try_from_birthdate(date, 861, Gender::Male).map(|pesel| pesel.gender()),
+            Ok(Gender::Male)
+        );
+        assert_eq!(
+            Pesel::try_from_birthdate(date, 1000, Gender::Male),
+            Err(ValidationError::Ordinal)
+        );
+        assert_eq!(
+            Pesel::try_from_birthdate(BirthDate { month: 13, ..date }, 861, Gender::Male),
+            Err(ValidationError::InvalidMonth)
+        );
+        assert_eq!(
+            Pesel::try_from_birthdate(
+                BirthDate {
+                    month: 2,
+                    day: 30,
+                    ..date
+                },
+                861,
+                Gender::Male
+            ),
+            Err(ValidationError::InvalidDay)
+        );
+        // The day must not carry into the month section.
+        for day in [0, 32, 104, 255] {
+            assert_eq!(
+                Pesel::try_from_birthdate(BirthDate { day, ..date }, 861, Gender::Female),
+                Err(ValidationError::InvalidDay)
+            );
+        }
+
+        for (year, leap) in [(2000, true), (2024, true), (1900, false), (2100, false)] {
+            let date = BirthDate {
+                year,
+                month: 2,
+                day: 29,
+            };
+            assert_eq!(
+                Pesel::try_from_birthdate(date, 861, Gender::Female).is_ok(),
+                leap
+            );
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "chrono")]
+    fn try_from_date_and_serial() {
+        let date = NaiveDate::from_ymd_opt(2002, 09, 04).unwrap();
+        assert_eq!(
+            Pesel::try_from_date_and_serial(date, 8616),
+            Ok(PESEL1.to_owned())
+        );
+        assert_eq!(
+            Pesel::try_from_date_and_serial(date, 10000),
+            Err(ValidationError::Ordinal)
+        );
+
+        let date = NaiveDate::from_ymd_opt(1900, 01, 01).unwrap();
+        assert_eq!(
+            Pesel::try_from_date_and_serial(date, 2854),
+            Ok(PESEL3.to_owned())
+        );
+
+        let date = NaiveDate::from_ymd_opt(2300, 01, 01).unwrap();
+        assert_eq!(
+            Pesel::try_from_date_and_serial(date, 2854),
+            Err(ValidationError::InvalidMonth)
+        );
+    }
+
+    #[test]
+    fn from_os_str() {
+        use std::ffi::OsStr;
+
+        assert_eq!(
+            Pesel::from_os_str(OsStr::new("00010128545")),
+            Ok(PESEL3.to_owned())
+        );
+        assert_eq!(
+            Pesel::from_os_str(OsStr::new("0229048616x")),
+            Err(ValidationError::NonNumeric)
+        );
+        assert_eq!(
+            Pesel::from_os_str(OsStr::new("")),
+            Err(ValidationError::TooShort(0))
+        );
+
+        #[cfg(unix)]
+        {
+            use std::os::unix::ffi::OsStrExt;
+
+            assert_eq!(
+                Pesel::from_os_str(OsStr::from_bytes(b"0229048616\xff")),
+                Err(ValidationError::NonNumeric)
+            );
+        }
+    }
+
+    #[test]
+    fn parse_lenient() {
+        assert_eq!(
+            Pesel::parse_lenient("\"02290486168\""),
+            Ok(PESEL1.to_owned())
+        );
+        assert_eq!(Pesel::parse_lenient("'00010128545'"), Ok(PESEL3.to_owned()));
+        assert_eq!(
+            Pesel::parse_lenient("\u{feff}02290486168"),
+            Ok(PESEL1.to_owned())
+        );
+        assert_eq!(
+            Pesel::parse_lenient("\u{feff} \" 02290486168 \"\r\n"),
+            Ok(PESEL1.to_owned())
+        );
+        assert_eq!(
+            Pesel::parse_lenient("\"02290486168'"),
+            Err(ValidationError::NonNumeric)
+        );
+        assert_eq!(
+            Pesel::parse_lenient("\"02290486167\""),
+            Err(ValidationError::ControlDigit)
+        );
+    }
+
+    #[test]
+    fn parse_spreadsheet() {
+        assert_eq!(
+            Pesel::parse_spreadsheet("2.290486168E+09"),
+            Ok(PESEL1.to_owned())
+        );
+        assert_eq!(
+            Pesel::parse_spreadsheet("6.0032417874e10"),
+            Ok(PESEL5.to_owned())
+        );
+        assert_eq!(Pesel::parse_spreadsheet("10128545"), Ok(PESEL3.to_owned()));
+        assert_eq!(
+            Pesel::parse_spreadsheet("2290486168.0"),
+            Ok(PESEL1.to_owned())
+        );
+        assert_eq!(
+            Pesel::parse_spreadsheet("2.29049E+09"),
+            Err(ValidationError::ControlDigit)
+        );
+        assert_eq!(
+            Pesel::parse_spreadsheet("2.2904861685E+09"),
+            Err(ValidationError::NonNumeric)
+        );
+        assert_eq!(
+            Pesel::parse_spreadsheet("2.29E+100"),
+            Err(ValidationError::TooLong(101))
+        );
+        assert_eq!(
+            Pesel::parse_spreadsheet("1e2147483647"),
+            Err(ValidationError::NonNumeric)
+        );
+        assert_eq!(
+            Pesel::parse_spreadsheet("1e-2147483648"),
+            Err(ValidationError::NonNumeric)
+        );
+        assert_eq!(
+            Pesel::parse_spreadsheet("1.żE1"),
+            Err(ValidationError::NonNumeric)
+        );
+    }
+
     #[test]
     fn invalid_pesels() {
         assert_eq!(Pesel::try_from(4355), Err(ValidationError::TooShort(4)));
@@ -193,7 +1182,7 @@ mod tests {
         );
         assert_eq!(
             Pesel::try_from(99990486167),
-            Err(ValidationError::BirthDate)
+            Err(ValidationError::InvalidMonth)
         );
         assert_eq!(
             Pesel::try_from(02290486167),
@@ -201,6 +1190,38 @@ mod tests {
         );
     }
 
+    #[test]
+    fn try_from_u64_ref() {
+        let pesels: &[u64] = &[02290486168, 01302534699, 02290486167];
+        assert_eq!(
+            pesels.iter().map(Pesel::try_from).collect::<Vec<_>>(),
+            [
+                Ok(PESEL1.to_owned()),
+                Ok(PESEL2.to_owned()),
+                Err(ValidationError::ControlDigit)
+            ]
+        );
+    }
+
+    #[test]
+    fn try_from_f64() {
+        assert_eq!(Pesel::try_from(2290486168.0), Ok(PESEL1.to_owned()));
+        assert_eq!(Pesel::try_from(98250993285.0), Ok(PESEL4.to_owned()));
+        assert_eq!(
+            Pesel::try_from(2290486168.5),
+            Err(ValidationError::NonNumeric)
+        );
+        assert_eq!(
+            Pesel::try_from(-2290486168.0),
+            Err(ValidationError::NonNumeric)
+        );
+        assert_eq!(Pesel::try_from(f64::NAN), Err(ValidationError::NonNumeric));
+        assert_eq!(
+            Pesel::try_from(435585930294485.0),
+            Err(ValidationError::TooLong(15))
+        );
+    }
+
     #[test]
     fn try_from_strings() {
         assert_eq!(
@@ -225,6 +1246,186 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    #[cfg(feature = "sqlx")]
+    async fn sqlx_encode_decode() {
+        use sqlx::{Connection, SqliteConnection};
+
+        let mut connection = SqliteConnection::connect("sqlite::memory:").await.unwrap();
+
+        let (pesel, text): (Pesel, String) = sqlx::query_as("SELECT ?, ?")
+            .bind(PESEL3.to_owned())
+            .bind(PESEL3.to_owned())
+            .fetch_one(&mut connection)
+            .await
+            .unwrap();
+        assert_eq!(pesel, PESEL3.to_owned());
+        assert_eq!(text, "00010128545");
+
+        sqlx::query_as::<_, (Pesel,)>("SELECT '02290486167'")
+            .fetch_one(&mut connection)
+            .await
+            .expect_err("Invalid PESEL");
+    }
+
+    #[test]
+    #[cfg(feature = "diesel")]
+    fn diesel_to_from_sql() {
+        use diesel::{
+            deserialize::FromSql,
+            mysql::{Mysql, MysqlType, MysqlValue},
+            query_builder::{bind_collector::RawBytesBindCollector, BindCollector},
+            sql_types::Text,
+        };
+
+        let mut collector = RawBytesBindCollector::<Mysql>::new();
+        collector
+            .push_bound_value::<Text, _>(&*PESEL3, &mut ())
+            .unwrap();
+        let bytes = collector.binds[0].as_deref().unwrap();
+        assert_eq!(bytes, b"00010128545");
+
+        let value = MysqlValue::new(bytes, MysqlType::String);
+        assert_eq!(
+            <Pesel as FromSql<Text, Mysql>>::from_sql(value).unwrap(),
+            PESEL3.to_owned()
+        );
+
+        let value = MysqlValue::new(b"02290486167", MysqlType::String);
+        <Pesel as FromSql<Text, Mysql>>::from_sql(value).expect_err("Invalid PESEL");
+    }
+
+    #[test]
+    fn value() {
+        assert_eq!("02290486168".parse::<Pesel>().unwrap().value(), 02290486168);
+        assert_eq!(Pesel::try_from(98250993285).unwrap().value(), 98250993285);
+    }
+
+    #[test]
+    fn digits() {
+        assert_eq!(PESEL3.digit_at(0), Some(0));
+        assert_eq!(PESEL3.digit_at(4), Some(0));
+        assert_eq!(PESEL3.digit_at(10), Some(5));
+        assert_eq!(PESEL3.digit_at(11), None);
+        assert_eq!(
+            PESEL3.iter_digits_rev().collect::<Vec<_>>(),
+            [5, 4, 5, 8, 2, 1, 0, 1, 0, 0, 0]
+        );
+    }
+
+    #[test]
+    fn validate_self() {
+        assert_eq!(PESEL1.validate_self(), Ok(()));
+        assert_eq!(
+            Pesel::new_unchecked(02290486167).validate_self(),
+            Err(ValidationError::ControlDigit)
+        );
+        assert_eq!(
+            Pesel::new_unchecked(02293186168).validate_self(),
+            Err(ValidationError::InvalidDay)
+        );
+    }
+
+    #[test]
+    fn section() {
+        assert_eq!(
+            Section::ALL.map(|section| PESEL1.section(section)),
+            [02, 29, 04, 8616, 8]
+        );
+    }
+
+    #[test]
+    fn masked() {
+        assert_eq!(PESEL1.masked(), "022904*****");
+
+        let date_only = MaskOptions {
+            mask_date: true,
+            mask_serial: false,
+            mask_control: false,
+        };
+        assert_eq!(PESEL1.masked_with(date_only), "******86168");
+
+        let everything = MaskOptions {
+            mask_date: true,
+            ..Default::default()
+        };
+        assert_eq!(PESEL1.masked_with(everything), "***********");
+    }
+
+    #[test]
+    fn transposition_candidates() {
+        // 02290486168 with the 8th and 9th digits swapped.
+        let typo = Pesel::new_unchecked(02290481668);
+        let candidates = typo.transposition_candidates();
+        assert!(candidates.contains(&PESEL1));
+        for candidate in candidates {
+            assert_ne!(candidate, typo);
+            assert_eq!(validate(&candidate), Ok(()));
+        }
+    }
+
+    #[test]
+    fn edit_distance_digits() {
+        let other = Pesel::try_from(02290486113).unwrap();
+        assert_eq!(PESEL1.edit_distance_digits(&other), 2);
+        assert_eq!(other.edit_distance_digits(&*PESEL1), 2);
+        assert_eq!(PESEL1.edit_distance_digits(&*PESEL1), 0);
+        assert_eq!(
+            PESEL3.edit_distance_digits(&bit_fields::Pesel::try_from(00010128545).unwrap()),
+            0
+        );
+    }
+
+    #[test]
+    fn write_digits() {
+        use std::fmt::Write;
+
+        let mut s = String::from("PESEL: ");
+        PESEL3.write_digits(&mut s).unwrap();
+        write!(s, ", ").unwrap();
+        PESEL4.write_digits(&mut s).unwrap();
+        assert_eq!(s, "PESEL: 00010128545, 98250993285");
+    }
+
+    #[test]
+    #[cfg(feature = "chrono")]
+    fn next_valid() {
+        for pesel in [&*PESEL1, &*PESEL2, &*PESEL3, &*PESEL4, &*PESEL5] {
+            let next = pesel.next_valid().unwrap();
+            assert!(next > *pesel);
+            assert_eq!(next.date_of_birth(), pesel.date_of_birth());
+            assert_eq!(next.ordinal_section(), pesel.ordinal_section() + 1);
+            assert_eq!(validate(&next), Ok(()));
+        }
+
+        let last_of_day = Pesel::try_from(append_control_digit(0229309999)).unwrap();
+        let next = last_of_day.next_valid().unwrap();
+        assert_eq!(
+            next.date_of_birth(),
+            NaiveDate::from_ymd_opt(2002, 10, 01).unwrap()
+        );
+        assert_eq!(next.ordinal_section(), 0000);
+
+        let last = Pesel::try_from(append_control_digit(9992319999)).unwrap();
+        assert_eq!(last.next_valid(), None);
+        assert_eq!(
+            Pesel::new_unchecked(0).next_valid().unwrap().value(),
+            00010100008
+        );
+    }
+
+    #[test]
+    fn from_str() {
+        let pesel = "00010128545".parse::<Pesel>().unwrap();
+        assert_eq!(pesel, PESEL3.to_owned());
+        assert_eq!(pesel.year_section(), 00);
+        assert_eq!(pesel.to_string(), "00010128545");
+
+        assert_eq!(PESEL1.to_string(), "02290486168");
+        assert_eq!(PESEL4.to_string(), "98250993285");
+        "02290486167".parse::<Pesel>().expect_err("Invalid PESEL");
+    }
+
     #[test]
     #[cfg(feature = "serde")]
     fn deserialize() {
@@ -234,28 +1435,19 @@ mod tests {
             PESEL1.to_owned(),
             from_value::<Pesel>(json!("02290486168")).expect("Valid PESEL")
         );
-        assert_eq!(
-            PESEL1.to_owned(),
-            from_value::<Pesel>(json!(02290486168u64)).expect("Valid PESEL")
-        );
+        from_value::<Pesel>(json!(02290486168u64)).expect_err("Leading zeros lost");
 
         assert_eq!(
             PESEL2.to_owned(),
             from_value::<Pesel>(json!("01302534699")).expect("Valid PESEL")
         );
-        assert_eq!(
-            PESEL2.to_owned(),
-            from_value::<Pesel>(json!(01302534699u64)).expect("Valid PESEL")
-        );
+        from_value::<Pesel>(json!(01302534699u64)).expect_err("Leading zeros lost");
 
         assert_eq!(
             PESEL3.to_owned(),
             from_value::<Pesel>(json!("00010128545")).expect("Valid PESEL")
         );
-        assert_eq!(
-            PESEL3.to_owned(),
-            from_value::<Pesel>(json!(00010128545u64)).expect("Valid PESEL")
-        );
+        from_value::<Pesel>(json!(00010128545u64)).expect_err("Leading zeros lost");
 
         assert_eq!(
             PESEL4.to_owned(),
@@ -276,6 +1468,67 @@ mod tests {
         );
     }
 
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serialize() {
+        use serde_json::{from_value, json, to_value};
+
+        assert_eq!(to_value(&*PESEL3).unwrap(), json!("00010128545"));
+        assert_eq!(
+            from_value::<Pesel>(to_value(&*PESEL1).unwrap()).unwrap(),
+            PESEL1.to_owned()
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn deserialize_lenient() {
+        #[derive(Debug, serde::Deserialize)]
+        struct Person {
+            #[serde(deserialize_with = "super::deserialize_lenient")]
+            pesel: Pesel,
+        }
+
+        let person: Person = serde_json::from_str(r#"{ "pesel": " \"02290486168\"\n" }"#).unwrap();
+        assert_eq!(person.pesel, PESEL1.to_owned());
+
+        let person: Person = serde_json::from_str(r#"{ "pesel": 98250993285 }"#).unwrap();
+        assert_eq!(person.pesel, PESEL4.to_owned());
+
+        serde_json::from_str::<Person>(r#"{ "pesel": " 02290486167 " }"#)
+            .expect_err("Invalid PESEL");
+        serde_json::from_str::<Person>(r#"{ "pesel": "not a pesel" }"#).expect_err("Invalid PESEL");
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn pesel_json() {
+        use serde_json::{from_value, json, to_value};
+
+        let value = json!({
+            "pesel": "00010128545",
+            "year": 1900,
+            "month": 1,
+            "day": 1,
+            "gender": "Female",
+        });
+        assert_eq!(to_value(PeselJson(PESEL3.to_owned())).unwrap(), value);
+        assert_eq!(
+            from_value::<PeselJson>(value).unwrap(),
+            PeselJson(PESEL3.to_owned())
+        );
+        assert_eq!(
+            from_value::<PeselJson>(json!({ "pesel": "02290486168" })).unwrap(),
+            PeselJson(PESEL1.to_owned())
+        );
+
+        from_value::<PeselJson>(json!({ "pesel": "00010128545", "year": 2000 }))
+            .expect_err("Inconsistent details");
+        from_value::<PeselJson>(json!({ "pesel": "00010128545", "gender": "Male" }))
+            .expect_err("Inconsistent details");
+        from_value::<PeselJson>(json!({ "pesel": "02290486167" })).expect_err("Invalid PESEL");
+    }
+
     #[test]
     #[cfg(feature = "serde")]
     fn invalid_deserialize() {
@@ -38,6 +38,26 @@
 //! they're returning is valid, unlike the structs who are guaranteed to always return a valid
 //! value.
 //!
+//! # Features
+//!
+//! - `chrono` (default) - Dates of birth as [`chrono::NaiveDate`], see
+//!   [`PeselTrait::date_of_birth`]. Without it validation still checks the date of birth, use
+//!   [`PeselTrait::birth_date_parts`] to read it.
+//! - `serde` - Serialization and deserialization of both PESEL structs.
+//!   [`crate::human_redable::Pesel`] serializes as an 11 character string and rejects numbers
+//!   shorter than 11 digits, which have lost their leading zeros. **This is a breaking change,**
+//!   earlier versions serialized it as a number. [`crate::bit_fields::Pesel`] keeps serializing
+//!   as a number so its existing data stays compatible, prefer
+//!   [`crate::human_redable::Pesel`] for data leaving your program.
+//! - `rand` - Random PESEL generation, see [`crate::random`]. Enables `chrono`.
+//! - `sqlx` - Storing [`crate::human_redable::Pesel`] in text columns using `sqlx`.
+//! - `diesel` - Storing [`crate::human_redable::Pesel`] in text columns using `diesel`.
+//! - `rayon` - Validating many PESELs in parallel, see [`validate_many_par`].
+//! - `clock` - Enables the `clock` feature of `chrono`, together with `rand` generating PESELs of
+//!   a given age, see [`crate::random::generate_for_age`].
+//! - `time` - Dates of birth as [`time::Date`], see [`PeselTrait::birth_date_time`]. Together
+//!   with `default-features = false` it replaces `chrono` if your project standardizes on `time`.
+//!
 //! # Examples
 //!
 //! Function that takes a name and welcomes the person based on date of birth and gender from the
@@ -49,7 +69,7 @@
 //! fn welcome(first_name: &str, pesel: u64) {
 //!     match Pesel::try_from(pesel) {
 //!         Ok(pesel) => {
-//!             if pesel.date_of_birth() > NaiveDate::from_ymd_opt(2015, 1, 1).unwrap() {
+//!             if pesel.year() >= 2015 {
 //!                 let gender = if pesel.gender() == Gender::Male { "boy" } else { "girl" };
 //!                 println!("Wow {first_name}! You're such a young {gender}!");
 //!             } else {
@@ -75,57 +95,241 @@
 //!
 //!     let mut oldest_index = 0;
 //!     pesels.iter().skip(1).enumerate().for_each(|(i, pesel)| {
-//!         if pesels[oldest_index].date_of_birth() < pesel.date_of_birth() {
+//!         if pesels[oldest_index].birth_date_parts() < pesel.birth_date_parts() {
 //!             oldest_index = i;
 //!         }
 //!     });
 //!
-//!     let date_of_birth = pesels[oldest_index].date_of_birth();
+//!     let date_of_birth = pesels[oldest_index].birth_date_iso();
 //!     println!("PESEL nr. {oldest_index} is the oldest! Born at {date_of_birth}")
 //! }
 //! ```
 
 pub mod bit_fields;
+pub mod gender;
 pub mod human_redable;
+#[cfg(feature = "rand")]
+pub mod random;
+pub mod set;
 
+pub use gender::Gender;
+pub use set::PeselSet;
+
+#[cfg(feature = "chrono")]
 pub use chrono;
+#[cfg(feature = "diesel")]
+pub use diesel;
+#[cfg(feature = "rand")]
+pub use rand;
+#[cfg(feature = "rayon")]
+pub use rayon;
 #[cfg(feature = "serde")]
 pub use serde;
+#[cfg(feature = "sqlx")]
+pub use sqlx;
 pub use thiserror;
+#[cfg(feature = "time")]
+pub use time;
 
+/// Commonly used items, exporting both PESEL structs under distinct names.
+///
+/// ```rust
+/// use pesel_rs::prelude::*;
+///
+/// let human = HumanPesel::try_from(2290486168).unwrap();
+/// let bit = BitPesel::try_from(2290486168).unwrap();
+/// assert_eq!(human.birth_date_parts(), bit.birth_date_parts());
+/// ```
 pub mod prelude {
-    pub use crate::{validate, Gender, PeselTrait};
+    /// [`crate::bit_fields::Pesel`].
+    pub use crate::bit_fields::Pesel as BitPesel;
+    /// [`crate::human_redable::Pesel`].
+    pub use crate::human_redable::Pesel as HumanPesel;
+    pub use crate::{gender, validate, Gender, PeselSections, PeselTrait};
+    #[cfg(feature = "chrono")]
     pub use chrono::NaiveDate;
 }
 
+/// Functions extracting details from a human readable PESEL, also available in the lib root.
+///
+/// ```rust
+/// use pesel_rs::extract;
+///
+/// assert_eq!(extract::day(2290486168u64), 4);
+/// assert_eq!(extract::month(2290486168u64), Some(9));
+/// assert_eq!(extract::year(2290486168u64), 2002);
+/// ```
+pub mod extract {
+    #[cfg(feature = "chrono")]
+    pub use crate::date_of_birth;
+    pub use crate::{
+        control_section, day, day_section, gender, month, month_section, ordinal_section,
+        to_digits, year, year_section,
+    };
+}
+
+#[cfg(feature = "chrono")]
 use chrono::NaiveDate;
 use thiserror::Error;
 
+// Both layouts are plain numbers, keep them shareable between threads.
+const _: fn() = || {
+    fn assert_send_sync<T: Send + Sync>() {}
+    assert_send_sync::<bit_fields::Pesel>();
+    assert_send_sync::<human_redable::Pesel>();
+};
+
+/// PESEL split into human readable parts, see [`PeselTrait::decompose`].
 #[derive(Debug, Clone, PartialEq, Eq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-pub enum Gender {
-    Male,
-    Female,
+pub struct Decomposed {
+    pub year: u16,
+    pub month: u8,
+    pub day: u8,
+    /// Ordinal section without the gender digit, see [`PeselTrait::serial_number`].
+    pub serial: u16,
+    pub gender: Gender,
+    pub control: u8,
+}
+
+/// Named section of a PESEL, see [`PeselTrait::section`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Section {
+    Year,
+    Month,
+    Day,
+    Ordinal,
+    Control,
+}
+
+impl Section {
+    /// All sections, in the order they appear in the PESEL.
+    pub const ALL: [Section; 5] = [
+        Section::Year,
+        Section::Month,
+        Section::Day,
+        Section::Ordinal,
+        Section::Control,
+    ];
+}
+
+/// Sections hidden by [`PeselTrait::masked_with`].
+///
+/// By default the date of birth stays visible while the serial and control sections are hidden.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MaskOptions {
+    /// Hide the year, month and day sections.
+    pub mask_date: bool,
+    /// Hide the ordinal section, including the gender digit.
+    pub mask_serial: bool,
+    /// Hide the control digit.
+    pub mask_control: bool,
+}
+
+impl Default for MaskOptions {
+    fn default() -> Self {
+        Self {
+            mask_date: false,
+            mask_serial: true,
+            mask_control: true,
+        }
+    }
+}
+
+/// Date of birth without depending on `chrono`, see [`PeselTrait::birth_date_parts`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BirthDate {
+    pub year: u16,
+    pub month: u8,
+    pub day: u8,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Error)]
+#[non_exhaustive]
 pub enum ValidationError {
     #[error("Pesel is too short.")]
     TooShort(usize),
     #[error("Pesel is too long.")]
     TooLong(usize),
+    #[deprecated(note = "validation returns `InvalidMonth` or `InvalidDay` instead")]
     #[error("Pesel has an invalid date of birth.")]
     BirthDate,
+    /// The month section doesn't encode a month, or a year between 1800 and 2299.
+    #[error("Pesel has an invalid month of birth.")]
+    InvalidMonth,
+    /// The day section is out of range of the month of birth, e.g. February 30th.
+    #[error("Pesel has an invalid day of birth.")]
+    InvalidDay,
     #[error("Pesel has an invalid control digit.")]
     ControlDigit,
+    #[error("Pesel has an invalid ordinal number.")]
+    Ordinal,
+    #[error("Pesel contains non-numeric characters.")]
+    NonNumeric,
+    /// All 11 digits are the same, e.g. the `00000000000` or `11111111111` placeholders.
+    #[error("Pesel consists of a single repeated digit.")]
+    RepeatedDigits,
+}
+
+impl ValidationError {
+    /// Human readable message describing the error.
+    ///
+    /// Unlike matching on the variants, this keeps working when new variants are added.
+    pub const fn user_message(&self) -> &'static str {
+        match self {
+            Self::TooShort(_) => "Pesel is too short.",
+            Self::TooLong(_) => "Pesel is too long.",
+            #[allow(deprecated)]
+            Self::BirthDate => "Pesel has an invalid date of birth.",
+            Self::InvalidMonth => "Pesel has an invalid month of birth.",
+            Self::InvalidDay => "Pesel has an invalid day of birth.",
+            Self::ControlDigit => "Pesel has an invalid control digit.",
+            Self::Ordinal => "Pesel has an invalid ordinal number.",
+            Self::NonNumeric => "Pesel contains non-numeric characters.",
+            Self::RepeatedDigits => "Pesel consists of a single repeated digit.",
+        }
+    }
 }
 
-const PESEL_WEIGHTS: [u8; 11] = [1, 3, 7, 9, 1, 3, 7, 9, 1, 3, 1];
+/// Number of distinct serial numbers available for a single date of birth, see
+/// [`PeselTrait::serial_number`].
+pub const SERIALS_PER_DATE: u16 = 1000;
+
+/// Number of digits of a PESEL.
+pub const PESEL_LENGTH: usize = 11;
+
+/// Number of digits of the serial number, see [`PeselTrait::serial_number`].
+pub const SERIAL_DIGITS: usize = 3;
+
+/// Position of the year section in the human readable string.
+pub const YEAR_SECTION_POSITION: usize = 0;
+/// Number of digits of the year section.
+pub const YEAR_SECTION_DIGITS: usize = 2;
+/// Position of the month section in the human readable string.
+pub const MONTH_SECTION_POSITION: usize = 2;
+/// Number of digits of the month section.
+pub const MONTH_SECTION_DIGITS: usize = 2;
+/// Position of the day section in the human readable string.
+pub const DAY_SECTION_POSITION: usize = 4;
+/// Number of digits of the day section.
+pub const DAY_SECTION_DIGITS: usize = 2;
+/// Position of the ordinal section in the human readable string.
+pub const ORDINAL_SECTION_POSITION: usize = 6;
+/// Number of digits of the ordinal section.
+pub const ORDINAL_SECTION_DIGITS: usize = 4;
+/// Position of the control digit in the human readable string.
+pub const CONTROL_SECTION_POSITION: usize = 10;
+/// Number of digits of the control section.
+pub const CONTROL_SECTION_DIGITS: usize = 1;
+
+/// Weight of each digit in the checksum, most significant first, see [`checksum_breakdown`].
+pub const PESEL_WEIGHTS: [u8; PESEL_LENGTH] = [1, 3, 7, 9, 1, 3, 7, 9, 1, 3, 1];
 
 #[cfg(feature = "serde")]
 #[cfg_attr(feature = "serde", macro_export)]
 macro_rules! impl_pesel_visitor {
-    ($name:ident) => {
+    ($name:ident $(, min_digits = $min_digits:literal)?) => {
         pub struct PeselVisitor;
 
         impl<'de> serde::de::Visitor<'de> for PeselVisitor {
@@ -139,6 +343,14 @@ macro_rules! impl_pesel_visitor {
             where
                 E: serde::de::Error,
             {
+                $(
+                    if v.checked_ilog10().map_or(1, |log| log + 1) < $min_digits {
+                        return Err(serde::de::Error::custom(
+                            "Pesel number lost its leading zeros, use a string instead.",
+                        ));
+                    }
+                )?
+
                 $name::try_from(v).map_err(|err| serde::de::Error::custom(err.to_string()))
             }
 
@@ -168,8 +380,8 @@ macro_rules! impl_pesel_visitor {
 #[cfg(feature = "serde")]
 #[cfg_attr(feature = "serde", macro_export)]
 macro_rules! impl_pesel_deserializer {
-    ($name:ident) => {
-        impl_pesel_visitor!($name);
+    ($name:ident $(, min_digits = $min_digits:literal)?) => {
+        impl_pesel_visitor!($name $(, min_digits = $min_digits)?);
 
         impl<'de> serde::Deserialize<'de> for $name {
             fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
@@ -201,41 +413,52 @@ pub const fn month_to_section(month: u8, year: u16) -> Option<u8> {
     if !(1 <= month && month <= 12) {
         return None;
     }
-    if !(1800 <= year && year <= 2299) {
-        return None;
+
+    let mut i = 0;
+    while i < MONTH_SHIFTS.len() {
+        let (first_year, last_year, shift) = MONTH_SHIFTS[i];
+        if first_year <= year && year <= last_year {
+            return Some(month + shift);
+        }
+        i += 1;
     }
 
-    // TODO: Find a better conversion method
-    let base = ((year / 100) - 10) as u8;
-    let shift = match base {
-        8 => 80,
-        9 => 0,
-        base => (base + 1) * 20,
-    };
+    None
+}
+
+/// Month section shift of each supported range of years, `(first_year, last_year, shift)`.
+const MONTH_SHIFTS: [(u16, u16, u8); 5] = [
+    (1800, 1899, 80),
+    (1900, 1999, 0),
+    (2000, 2099, 20),
+    (2100, 2199, 40),
+    (2200, 2299, 60),
+];
 
-    Some(month + shift)
+/// First year of the century encoded by the shift of the month section, e.g. `2000` for `29`.
+///
+/// A month section stored without the shift always decodes as `1900`.
+pub const fn century_from_section(month_section: u8) -> u16 {
+    // Each century shifts the month section by 20, the 1800s wrap around to the last band.
+    match month_section / 20 {
+        0 => 1900,
+        1 => 2000,
+        2 => 2100,
+        3 => 2200,
+        _ => 1800,
+    }
 }
 
+/// Year of birth encoded by the month and year sections, e.g. `2002` for `29` and `02`.
 pub const fn year_from_sections(month_section: u8, year_section: u8) -> u16 {
-    let shift = ((month_section / 10) / 2) * 2;
-
-    (match shift {
-        8 => 1800,
-        shift => 1900 + (shift as u16) * 50,
-    } + year_section as u16)
+    century_from_section(month_section) + year_section as u16
 }
 
-/// Trait for implementing a [PESEL](https://en.wikipedia.org/wiki/PESEL).
-///
-/// It's required for a PESEL to implement [`TryFrom<u64>`] and [`Into<u64>`] (for `Self` and `&Self`)
-/// where the [`u64`] PESEL must be represented as a human readable number.
+/// Object safe part of [`PeselTrait`], extracting each section.
 ///
-/// The only required methods are for extracting each section. The rest is computed based on that.
-pub trait PeselTrait: TryFrom<u64> + Into<u64>
-where
-    u64: From<Self>,
-    for<'a> u64: From<&'a Self>,
-{
+/// Allows mixing layouts behind trait objects, e.g. `Vec<Box<dyn PeselSections>>`, see
+/// [`to_u64_dyn`].
+pub trait PeselSections {
     /// Day of birth section.
     fn day_section(&self) -> u8;
 
@@ -250,12 +473,74 @@ where
 
     /// Control section.
     fn control_section(&self) -> u8;
+}
+
+/// Human readable number of a PESEL behind a trait object.
+pub fn to_u64_dyn(pesel: &dyn PeselSections) -> u64 {
+    pesel.control_section() as u64
+        + pesel.ordinal_section() as u64 * 10
+        + pesel.day_section() as u64 * 100_000
+        + pesel.month_section() as u64 * 10_000_000
+        + pesel.year_section() as u64 * 1_000_000_000
+}
+
+/// Trait for implementing a [PESEL](https://en.wikipedia.org/wiki/PESEL).
+///
+/// It's required for a PESEL to implement [`TryFrom<u64>`] and [`Into<u64>`] (for `Self` and `&Self`)
+/// where the [`u64`] PESEL must be represented as a human readable number.
+///
+/// The only required methods are for extracting each section, see [`PeselSections`]. The rest is
+/// computed based on that.
+pub trait PeselTrait: PeselSections + TryFrom<u64> + Into<u64>
+where
+    u64: From<Self>,
+    for<'a> u64: From<&'a Self>,
+{
+    /// Validate the current contents of the PESEL, see [`validate`].
+    fn validate_self(&self) -> Result<(), ValidationError> {
+        validate(self.normalized_u64())
+    }
+
+    /// Control digit stored in the PESEL, same as [`PeselSections::control_section`].
+    fn stored_control(&self) -> u8 {
+        self.control_section()
+    }
+
+    /// Control digit computed from the first ten digits, see [`compute_control_digit`].
+    fn expected_control(&self) -> u8 {
+        compute_control_digit(self.normalized_u64() / 10)
+    }
+
+    /// Check if the stored control digit is the computed one.
+    fn control_matches(&self) -> bool {
+        self.stored_control() == self.expected_control()
+    }
+
+    /// Extract a section chosen at runtime.
+    fn section(&self, section: Section) -> u64 {
+        match section {
+            Section::Year => self.year_section() as u64,
+            Section::Month => self.month_section() as u64,
+            Section::Day => self.day_section() as u64,
+            Section::Ordinal => self.ordinal_section() as u64,
+            Section::Control => self.control_section() as u64,
+        }
+    }
 
     /// Day of birth.
     fn day(&self) -> u8 {
         self.day_section()
     }
 
+    /// First year of the century of birth, decoded from the shift of the month section, see
+    /// [`century_from_section`].
+    ///
+    /// Useful for detecting records migrated from systems storing the month without the shift,
+    /// as they all decode as `1900`.
+    fn century_from_shift(&self) -> u16 {
+        century_from_section(self.month_section())
+    }
+
     /// Month of birth.
     fn month(&self) -> u8 {
         match month(self) {
@@ -269,7 +554,19 @@ where
         year(self)
     }
 
+    /// Date of birth as an ISO 8601 `YYYY-MM-DD` string.
+    fn birth_date_iso(&self) -> String {
+        let BirthDate { year, month, day } = self.birth_date_parts();
+        format!("{year:04}-{month:02}-{day:02}")
+    }
+
+    /// First year of the decade of birth, e.g. `2000` for `2002`.
+    fn birth_decade(&self) -> u16 {
+        self.year() / 10 * 10
+    }
+
     /// Date of birth.
+    #[cfg(feature = "chrono")]
     fn date_of_birth(&self) -> NaiveDate {
         match date_of_birth(self) {
             Some(date_of_birth) => date_of_birth,
@@ -277,10 +574,367 @@ where
         }
     }
 
+    /// Day of the week of birth.
+    #[cfg(feature = "chrono")]
+    fn weekday_of_birth(&self) -> chrono::Weekday {
+        use chrono::Datelike;
+
+        self.date_of_birth().weekday()
+    }
+
+    /// `true` if born on a Saturday or Sunday.
+    #[cfg(feature = "chrono")]
+    fn born_on_weekend(&self) -> bool {
+        matches!(
+            self.weekday_of_birth(),
+            chrono::Weekday::Sat | chrono::Weekday::Sun
+        )
+    }
+
+    /// Date of birth as plain numbers, without going through `chrono`.
+    fn birth_date_parts(&self) -> BirthDate {
+        BirthDate {
+            year: self.year(),
+            month: self.month(),
+            day: self.day(),
+        }
+    }
+
+    /// Date of birth as a [`time::Date`], mirroring [`PeselTrait::date_of_birth`].
+    #[cfg(feature = "time")]
+    fn birth_date_time(&self) -> time::Date {
+        let BirthDate { year, month, day } = self.birth_date_parts();
+        let month = match time::Month::try_from(month) {
+            Ok(month) => month,
+            Err(_) => unreachable!(),
+        };
+        match time::Date::from_calendar_date(year as i32, month, day) {
+            Ok(date) => date,
+            Err(_) => unreachable!(),
+        }
+    }
+
+    /// Month of birth, `None` if the month section is invalid.
+    fn checked_month(&self) -> Option<u8> {
+        month(self).filter(|month| (1..=12).contains(month))
+    }
+
+    /// Date of birth, `None` if the date is invalid.
+    #[cfg(feature = "chrono")]
+    fn checked_date_of_birth(&self) -> Option<NaiveDate> {
+        date_of_birth(self)
+    }
+
+    /// Ordinal section, `None` if it is above `9999` or the PESEL has more than 11 digits.
+    ///
+    /// Always `Some` for [`crate::bit_fields::Pesel`], its
+    /// [`crate::bit_fields::Pesel::new_unchecked`] keeps only the last 11 digits.
+    fn checked_ordinal_section(&self) -> Option<u16> {
+        Some(self.ordinal_section())
+            .filter(|ordinal| *ordinal <= 9999 && self.normalized_u64() < 100_000_000_000)
+    }
+
+    /// Serial number, `None` if the ordinal section is invalid, see
+    /// [`PeselTrait::checked_ordinal_section`].
+    fn checked_serial_number(&self) -> Option<u16> {
+        self.checked_ordinal_section().map(|ordinal| ordinal / 10)
+    }
+
+    /// Age in full years on the given date, `0` if `on` is before the date of birth.
+    #[cfg(feature = "chrono")]
+    fn age_at(&self, on: NaiveDate) -> u32 {
+        use chrono::Datelike;
+
+        let date_of_birth = self.date_of_birth();
+        let mut age = on.year() - date_of_birth.year();
+        if (on.month(), on.day()) < (date_of_birth.month(), date_of_birth.day()) {
+            age -= 1;
+        }
+
+        age.max(0) as u32
+    }
+
+    /// Check if the date of birth is plausible for a PESEL registered in practice, i.e. not
+    /// before 1900 and not after `on`.
+    ///
+    /// This is a soft heuristic for data quality checks, dates outside of it are valid but
+    /// usually indicate migrated or fabricated data.
+    #[cfg(feature = "chrono")]
+    fn is_plausible_registration(&self, on: NaiveDate) -> bool {
+        self.year() >= 1900 && self.date_of_birth() <= on
+    }
+
     /// Gender.
     fn gender(&self) -> Gender {
         gender(self)
     }
+
+    /// Serial number, the first three digits of the ordinal section (without the gender digit).
+    fn serial_number(&self) -> u16 {
+        self.ordinal_section() / 10
+    }
+
+    /// Human readable number of the PESEL, regardless of the layout.
+    fn normalized_u64(&self) -> u64 {
+        u64::from(self)
+    }
+
+    /// Convert the PESEL into [`crate::human_redable::Pesel`], regardless of the layout.
+    fn normalize(self) -> human_redable::Pesel
+    where
+        Self: Sized,
+    {
+        human_redable::Pesel::new_unchecked(u64::from(self))
+    }
+
+    /// Number of serial numbers still free on a date of birth, given the PESELs already `used`
+    /// on that date.
+    ///
+    /// All `used` PESELs are assumed to share the same date of birth, PESELs differing only by
+    /// the gender digit count as one serial number.
+    fn remaining_serials(used: &[Self]) -> u16
+    where
+        Self: Sized,
+    {
+        let used: std::collections::BTreeSet<u16> =
+            used.iter().map(|pesel| pesel.serial_number()).collect();
+        SERIALS_PER_DATE - used.len() as u16
+    }
+
+    /// Split the PESEL into human readable parts.
+    fn decompose(&self) -> Decomposed {
+        Decomposed {
+            year: self.year(),
+            month: self.month(),
+            day: self.day(),
+            serial: self.serial_number(),
+            gender: self.gender(),
+            control: self.control_section(),
+        }
+    }
+
+    /// Consume the PESEL, returning `(year, month, day, serial, gender, control)`, see
+    /// [`PeselTrait::decompose`].
+    fn into_parts(self) -> (u16, u8, u8, u16, Gender, u8)
+    where
+        Self: Sized,
+    {
+        let Decomposed {
+            year,
+            month,
+            day,
+            serial,
+            gender,
+            control,
+        } = self.decompose();
+        (year, month, day, serial, gender, control)
+    }
+
+    /// Call `f` with the 11 digits of the PESEL, most significant first, without allocating.
+    fn with_digits<R>(&self, f: impl FnOnce(&[u8; 11]) -> R) -> R {
+        f(&to_digits(self))
+    }
+
+    /// Digit at `index`, counting from the left and including leading zeros, `None` if `index`
+    /// is above `10`.
+    fn digit_at(&self, index: usize) -> Option<u8> {
+        to_digits(self).get(index).copied()
+    }
+
+    /// Iterate over the 11 digits of the PESEL, least significant first.
+    fn iter_digits_rev(&self) -> impl Iterator<Item = u8> {
+        to_digits(self).into_iter().rev()
+    }
+
+    /// Write the PESEL as 11 ASCII digits into `buf`, without allocating.
+    fn write_to(&self, buf: &mut [u8; 11]) {
+        for (byte, digit) in buf.iter_mut().zip(to_digits(self)) {
+            *byte = b'0' + digit;
+        }
+    }
+
+    /// Write the PESEL as 11 zero-padded digits into `w`, without allocating.
+    fn write_digits<W: std::fmt::Write>(&self, w: &mut W) -> std::fmt::Result {
+        to_digits(self)
+            .into_iter()
+            .try_for_each(|digit| w.write_char((b'0' + digit) as char))
+    }
+
+    /// The PESEL as a zero-padded string, always exactly 11 characters long.
+    fn full_string(&self) -> String {
+        let mut buf = [0; 11];
+        self.write_to(&mut buf);
+        buf.iter().map(|&digit| digit as char).collect()
+    }
+
+    /// The PESEL with the serial and control sections replaced by `*`, e.g. `022904*****`, see
+    /// [`MaskOptions`].
+    fn masked(&self) -> String {
+        self.masked_with(MaskOptions::default())
+    }
+
+    /// The PESEL with the sections chosen by `options` replaced by `*`.
+    fn masked_with(&self, options: MaskOptions) -> String {
+        let mut buf = [0; 11];
+        self.write_to(&mut buf);
+
+        for (range, mask) in [
+            (
+                YEAR_SECTION_POSITION..ORDINAL_SECTION_POSITION,
+                options.mask_date,
+            ),
+            (
+                ORDINAL_SECTION_POSITION..CONTROL_SECTION_POSITION,
+                options.mask_serial,
+            ),
+            (CONTROL_SECTION_POSITION..PESEL_LENGTH, options.mask_control),
+        ] {
+            if mask {
+                buf[range].fill(b'*');
+            }
+        }
+
+        buf.iter().map(|&byte| byte as char).collect()
+    }
+
+    /// Valid PESELs differing by a swap of two adjacent digits among the first ten, with the
+    /// control digit recomputed, for suggesting corrections of typos.
+    ///
+    /// Works on PESELs with invalid contents too, e.g. created with
+    /// [`crate::human_redable::Pesel::new_unchecked`].
+    fn transposition_candidates(&self) -> Vec<human_redable::Pesel> {
+        let digits = to_digits(self);
+        (0..9)
+            .filter(|&i| digits[i] != digits[i + 1])
+            .filter_map(|i| {
+                let mut digits = digits;
+                digits.swap(i, i + 1);
+                let first_ten = digits[..10]
+                    .iter()
+                    .fold(0u64, |acc, digit| acc * 10 + *digit as u64);
+                human_redable::Pesel::try_from(append_control_digit(first_ten)).ok()
+            })
+            .collect()
+    }
+
+    /// The smallest valid PESEL numerically greater than this one, `None` past the last one.
+    ///
+    /// Invalid dates of birth are skipped a whole day section at a time, so walking the valid
+    /// space stays cheap.
+    fn next_valid(&self) -> Option<human_redable::Pesel> {
+        let mut first_ten = self.normalized_u64() / 10 + 1;
+        while first_ten < 10_000_000_000 {
+            let pesel = append_control_digit(first_ten);
+            if checked_birth_date(pesel).is_err() {
+                first_ten = (first_ten / 10_000 + 1) * 10_000;
+            } else if let Ok(pesel) = human_redable::Pesel::try_from(pesel) {
+                return Some(pesel);
+            } else {
+                first_ten += 1;
+            }
+        }
+
+        None
+    }
+
+    /// Check if the date of birth uses an "unknown" sentinel, see [`has_unknown_date_components`].
+    fn has_unknown_date_components(&self) -> bool {
+        has_unknown_date_components(self)
+    }
+
+    /// Compare with a PESEL of any layout by their human readable numbers.
+    ///
+    /// Unlike the derived [`Ord`] of the layouts, which compares their internal representation,
+    /// this ordering is the same for every layout, see [`Canonical`].
+    fn cmp_canonical<U: PeselTrait>(&self, other: &U) -> std::cmp::Ordering
+    where
+        u64: From<U>,
+        for<'a> u64: From<&'a U>,
+    {
+        self.normalized_u64().cmp(&other.normalized_u64())
+    }
+
+    /// Number of positions at which the 11 digits of the two PESELs differ (Hamming distance),
+    /// for clustering near-duplicates.
+    fn edit_distance_digits<U: PeselTrait>(&self, other: &U) -> u8
+    where
+        u64: From<U>,
+        for<'a> u64: From<&'a U>,
+    {
+        to_digits(self)
+            .iter()
+            .zip(to_digits(other))
+            .filter(|(a, b)| **a != *b)
+            .count() as u8
+    }
+}
+
+/// Wrapper comparing and hashing PESELs by their human readable number, see
+/// [`PeselTrait::normalized_u64`].
+///
+/// Equal PESELs are equal regardless of the layout, so a [`std::collections::HashSet`] of
+/// `Canonical` deduplicates them even when `T` is a type mixing both layouts. Likewise a
+/// [`std::collections::BTreeMap`] of `Canonical` iterates in numeric order, see
+/// [`PeselTrait::cmp_canonical`].
+///
+/// ```rust
+/// use pesel_rs::{bit_fields, human_redable, Canonical};
+///
+/// let bit_fields = bit_fields::Pesel::try_from(2290486168).unwrap();
+/// let human_redable = human_redable::Pesel::try_from(2290486168).unwrap();
+/// assert!(Canonical(bit_fields) == Canonical(human_redable));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Canonical<T: PeselTrait>(pub T)
+where
+    u64: From<T>,
+    for<'a> u64: From<&'a T>;
+
+impl<T: PeselTrait, U: PeselTrait> PartialEq<Canonical<U>> for Canonical<T>
+where
+    u64: From<T> + From<U>,
+    for<'a> u64: From<&'a T> + From<&'a U>,
+{
+    fn eq(&self, other: &Canonical<U>) -> bool {
+        self.0.normalized_u64() == other.0.normalized_u64()
+    }
+}
+
+impl<T: PeselTrait> Eq for Canonical<T>
+where
+    u64: From<T>,
+    for<'a> u64: From<&'a T>,
+{
+}
+
+impl<T: PeselTrait, U: PeselTrait> PartialOrd<Canonical<U>> for Canonical<T>
+where
+    u64: From<T> + From<U>,
+    for<'a> u64: From<&'a T> + From<&'a U>,
+{
+    fn partial_cmp(&self, other: &Canonical<U>) -> Option<std::cmp::Ordering> {
+        Some(self.0.cmp_canonical(&other.0))
+    }
+}
+
+impl<T: PeselTrait> Ord for Canonical<T>
+where
+    u64: From<T>,
+    for<'a> u64: From<&'a T>,
+{
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.0.cmp_canonical(&other.0)
+    }
+}
+
+impl<T: PeselTrait> std::hash::Hash for Canonical<T>
+where
+    u64: From<T>,
+    for<'a> u64: From<&'a T>,
+{
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.0.normalized_u64().hash(state);
+    }
 }
 
 /// Extract the day of birth section.
@@ -329,6 +983,7 @@ pub fn year(pesel: impl Into<u64>) -> u16 {
 }
 
 /// Extract date of birth.
+#[cfg(feature = "chrono")]
 pub fn date_of_birth(pesel: impl Into<u64>) -> Option<NaiveDate> {
     let pesel = pesel.into();
     NaiveDate::from_ymd_opt(
@@ -341,81 +996,539 @@ pub fn date_of_birth(pesel: impl Into<u64>) -> Option<NaiveDate> {
     )
 }
 
+/// Extract date of birth, telling apart an invalid month from an invalid day.
+pub(crate) fn checked_birth_date(pesel: u64) -> Result<BirthDate, ValidationError> {
+    let month = match month(pesel) {
+        Some(month @ 1..=12) => month,
+        _ => return Err(ValidationError::InvalidMonth),
+    };
+    let year = year(pesel);
+    let day = day(pesel);
+    if day < 1 || day > days_in_month(year, month) {
+        return Err(ValidationError::InvalidDay);
+    }
+
+    Ok(BirthDate { year, month, day })
+}
+
+/// Same as [`checked_birth_date`], as a [`NaiveDate`].
+#[cfg(feature = "chrono")]
+pub(crate) fn checked_date_of_birth(pesel: u64) -> Result<NaiveDate, ValidationError> {
+    let BirthDate { year, month, day } = checked_birth_date(pesel)?;
+    NaiveDate::from_ymd_opt(year as i32, month as u32, day as u32)
+        .ok_or(ValidationError::InvalidDay)
+}
+
 /// Extract gender.
 pub fn gender(pesel: impl Into<u64>) -> Gender {
-    if ordinal_section(pesel) % 2 == 0 {
-        Gender::Female
-    } else {
-        Gender::Male
+    gender::from_parity(ordinal_section(pesel))
+}
+
+/// Split the PESEL into its 11 digits, most significant first.
+pub fn to_digits(pesel: impl Into<u64>) -> [u8; 11] {
+    let mut rest = pesel.into();
+    let mut digits = [0; 11];
+    for digit in digits.iter_mut().rev() {
+        *digit = (rest % 10) as u8;
+        rest /= 10;
     }
+
+    digits
 }
 
-/// Check if the PESEL is valid.
-pub fn validate(pesel: impl Into<u64>) -> Result<(), ValidationError> {
+/// Check if the date of birth uses an "unknown" sentinel.
+///
+/// Some registry extensions mark an unknown day or month of birth with zero. Recognized patterns:
+/// - day section `00`
+/// - month section `00`, `20`, `40`, `60` or `80`, which decode to month zero
+///
+/// Such PESELs don't pass [`validate`], check them with this function before validating if you
+/// need to treat them specially.
+pub fn has_unknown_date_components(pesel: impl Into<u64>) -> bool {
     let pesel = pesel.into();
-    let mut pesel_str = pesel.to_string();
+    day_section(pesel) == 0 || month_section(pesel).is_multiple_of(20)
+}
 
-    if pesel_str.len() < 8 {
-        return Err(ValidationError::TooShort(pesel_str.len()));
+/// Compute the control digit for the first ten digits of a PESEL.
+///
+/// `first_ten` is the PESEL without its control digit, e.g. `229048616` for `02290486168`.
+pub const fn compute_control_digit(first_ten: u64) -> u8 {
+    let mut rest = first_ten;
+    let mut sum = 0u16;
+    let mut i = 10;
+    while i > 0 {
+        i -= 1;
+        sum += (rest % 10) as u16 * PESEL_WEIGHTS[i] as u16;
+        rest /= 10;
     }
 
-    if pesel_str.len() > 11 {
-        return Err(ValidationError::TooLong(pesel_str.len()));
-    }
+    ((10 - sum % 10) % 10) as u8
+}
 
-    if pesel_str.len() < 11 {
-        let mut new_value_str = "0".to_string();
+/// Compute the control digit of a PESEL from its decomposed fields, without assembling it.
+///
+/// # Errors
+/// Returns `None` if the date is invalid, isn't between `1800-01-01` and `2299-12-31`, or
+/// `ordinal` is above `9999`.
+pub fn control_digit_for(year: u16, month: u8, day: u8, ordinal: u16) -> Option<u8> {
+    assemble(year, month, day, ordinal)
+        .ok()
+        .map(control_section)
+}
 
-        for _ in (pesel_str.len() + 1)..11 {
-            new_value_str.push('0');
-        }
+/// Weighted contribution of each digit to the checksum, `(digit, weight, product)` most
+/// significant first.
+///
+/// The control digit has a weight of `1`, so the products of a valid PESEL sum to a multiple of
+/// `10`.
+pub fn checksum_breakdown(pesel: impl Into<u64>) -> [(u8, u8, u16); 11] {
+    let digits = to_digits(pesel);
+    std::array::from_fn(|i| {
+        (
+            digits[i],
+            PESEL_WEIGHTS[i],
+            digits[i] as u16 * PESEL_WEIGHTS[i] as u16,
+        )
+    })
+}
+
+/// Append the control digit to the first ten digits of a PESEL, see [`compute_control_digit`].
+///
+/// The result is not validated, which makes it handy for building PESELs invalid in other ways,
+/// e.g. with an impossible date of birth.
+pub const fn append_control_digit(first_ten: u64) -> u64 {
+    first_ten * 10 + compute_control_digit(first_ten) as u64
+}
 
-        pesel_str = new_value_str + &pesel_str;
+/// Assemble a human readable PESEL from its date of birth and ordinal section.
+///
+/// # Errors
+/// - [`ValidationError::InvalidMonth`] if `month` is not in range of `<1,12>` or `year` is not
+///   in range of `<1800,2299>`
+/// - [`ValidationError::InvalidDay`] if the day doesn't exist in the month
+/// - [`ValidationError::Ordinal`] if `ordinal` is not in range of `<0,9999>`
+pub(crate) const fn assemble(
+    year: u16,
+    month: u8,
+    day: u8,
+    ordinal: u16,
+) -> Result<u64, ValidationError> {
+    let month_section = match month_to_section(month, year) {
+        Some(month_section) => month_section,
+        None => return Err(ValidationError::InvalidMonth),
+    };
+    if day < 1 || day > days_in_month(year, month) {
+        return Err(ValidationError::InvalidDay);
+    }
+    if ordinal > 9999 {
+        return Err(ValidationError::Ordinal);
     }
 
-    if date_of_birth(pesel).is_none() {
-        return Err(ValidationError::BirthDate);
+    let first_ten = (year % 100) as u64 * 100_000_000
+        + month_section as u64 * 1_000_000
+        + day as u64 * 10_000
+        + ordinal as u64;
+
+    Ok(append_control_digit(first_ten))
+}
+
+/// Number of days in the month of the Gregorian calendar, `month` being in range of `<1,12>`.
+const fn days_in_month(year: u16, month: u8) -> u8 {
+    match month {
+        2 if year.is_multiple_of(4) && (!year.is_multiple_of(100) || year.is_multiple_of(400)) => {
+            29
+        }
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
     }
+}
 
-    let mut sum = 0;
-    for (i, digit) in pesel_str
-        .chars()
-        .take(11)
-        .map(|char| char.to_digit(10).unwrap())
-        .enumerate()
-    {
-        sum += (digit as u8) * PESEL_WEIGHTS[i];
+/// Convert a float holding a PESEL, e.g. from loosely typed JSON, into an integer.
+///
+/// All 11 digit numbers are below `2^53`, so they are represented exactly.
+pub(crate) fn u64_from_f64(value: f64) -> Result<u64, ValidationError> {
+    if !value.is_finite() || value < 0.0 || value.fract() != 0.0 {
+        return Err(ValidationError::NonNumeric);
+    }
+    if value >= 1e11 {
+        return Err(ValidationError::TooLong(value.log10() as usize + 1));
     }
 
-    if let Some(Some(last_digit)) = sum.to_string().chars().last().map(|char| char.to_digit(10)) {
-        if last_digit != 0 {
-            return Err(ValidationError::ControlDigit);
-        }
+    Ok(value as u64)
+}
+
+/// Check if the control digit of the PESEL is correct.
+///
+/// # Errors
+/// Returns `Err((expected, actual))` with both control digits if they differ.
+pub fn validate_control(pesel: impl Into<u64>) -> Result<(), (u8, u8)> {
+    let pesel = pesel.into();
+    let expected = compute_control_digit(pesel / 10);
+    let actual = control_section(pesel);
+
+    if expected == actual {
         Ok(())
     } else {
-        Err(ValidationError::ControlDigit)
+        Err((expected, actual))
     }
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, Error)]
-#[error("{0}")]
-pub enum PeselTryFromError<T> {
-    ValidationError(#[from] ValidationError),
-    Other(T),
+/// Check the number of digits of a PESEL.
+///
+/// Numbers lose their leading zeros, so anything from `8` (born in January 1900) to `11` digits
+/// is accepted. So do [`validate`] and the `TryFrom<&str>` and `FromStr` implementations, only
+/// [`validate_known_length`] and the functions built on it require exactly `11`.
+pub const fn validate_length(len: usize) -> Result<(), ValidationError> {
+    validate_length_at_least(len, 8)
 }
 
-#[macro_export]
-macro_rules! impl_try_from_str_for_pesel {
-    ($name:ident) => {
-        impl TryFrom<&str> for $name {
-            type Error = PeselTryFromError<std::num::ParseIntError>;
+const fn validate_length_at_least(len: usize, min_len: usize) -> Result<(), ValidationError> {
+    if len < min_len {
+        Err(ValidationError::TooShort(len))
+    } else if len > PESEL_LENGTH {
+        Err(ValidationError::TooLong(len))
+    } else {
+        Ok(())
+    }
+}
 
-            fn try_from(value: &str) -> Result<Self, Self::Error> {
-                let value = u64::from_str_radix(value, 10).map_err(PeselTryFromError::Other)?;
-                validate(value)?;
-                Self::try_from(value).map_err(PeselTryFromError::ValidationError)
-            }
-        }
+/// Check if the PESEL is valid.
+///
+/// Placeholder values made of a single repeated digit, e.g. `11111111111`, are always rejected,
+/// even if they happen to form a valid date and control digit like `22222222222`. All zeros are
+/// rejected as well, since `0` is [`ValidationError::TooShort`].
+pub fn validate(pesel: impl Into<u64>) -> Result<(), ValidationError> {
+    let pesel = pesel.into();
+    let len = pesel.checked_ilog10().map_or(1, |log| log as usize + 1);
+    validate_length(len)?;
+
+    if len == 11 && pesel % 11111111111 == 0 {
+        return Err(ValidationError::RepeatedDigits);
+    }
+
+    checked_birth_date(pesel)?;
+
+    if compute_control_digit(pesel / 10) != control_section(pesel) {
+        return Err(ValidationError::ControlDigit);
+    }
+
+    Ok(())
+}
+
+/// Check if the PESEL string is valid, requiring exactly 11 digits.
+///
+/// Unlike [`validate`], which can't tell leading zeros from missing digits, this rejects e.g.
+/// `"10128545"` while accepting `"00010128545"`. Prefer it whenever the original string is
+/// available.
+pub fn validate_known_length(digits: &str) -> Result<(), ValidationError> {
+    if !digits.bytes().all(|byte| byte.is_ascii_digit()) {
+        return Err(ValidationError::NonNumeric);
+    }
+
+    validate_length_at_least(digits.len(), PESEL_LENGTH)?;
+
+    if digits.bytes().all(|byte| byte == digits.as_bytes()[0]) {
+        return Err(ValidationError::RepeatedDigits);
+    }
+
+    // The length is already known, `validate` would count the leading zeros as missing digits.
+    let pesel = digits
+        .parse::<u64>()
+        .map_err(|_| ValidationError::NonNumeric)?;
+    checked_birth_date(pesel)?;
+    validate_control(pesel).map_err(|_| ValidationError::ControlDigit)
+}
+
+/// Loosened [`validate_known_length`] for data archaeology, checking only the length and the
+/// control digit.
+///
+/// Legacy datasets contain numbers with impossible dates of birth, e.g. February 30th, which
+/// still have to be loaded. Such numbers are not valid PESELs and will be rejected by the
+/// structs, single repeated digit placeholders are rejected here as well.
+pub fn validate_checksum_and_length_only(pesel: &str) -> Result<(), ValidationError> {
+    match validate_known_length(pesel) {
+        Err(ValidationError::InvalidMonth | ValidationError::InvalidDay) => validate_control(
+            pesel
+                .parse::<u64>()
+                .map_err(|_| ValidationError::NonNumeric)?,
+        )
+        .map_err(|_| ValidationError::ControlDigit),
+        result => result,
+    }
+}
+
+/// Loosened [`validate_known_length`] accepting PESELs with a "temporary" date of birth.
+///
+/// There are no officially reserved month values, the temporary ranges accepted here are the
+/// unknown date sentinels of [`has_unknown_date_components`]:
+/// - day section `00`
+/// - month section `00`, `20`, `40`, `60` or `80`
+///
+/// The remaining date section must still be in range, i.e. a month of `<1,12>` or a day of
+/// `<1,31>`, and the control digit is always checked.
+pub fn validate_allowing_temporary(pesel: &str) -> Result<(), ValidationError> {
+    match validate_known_length(pesel) {
+        Err(err @ (ValidationError::InvalidMonth | ValidationError::InvalidDay)) => {
+            let value = pesel
+                .parse::<u64>()
+                .map_err(|_| ValidationError::NonNumeric)?;
+            if !has_unknown_date_components(value) {
+                return Err(err);
+            }
+            let month_section = month_section(value);
+            if month_section > 92 || month_section % 20 > 12 {
+                return Err(ValidationError::InvalidMonth);
+            }
+            if day_section(value) > 31 {
+                return Err(ValidationError::InvalidDay);
+            }
+
+            validate_control(value).map_err(|_| ValidationError::ControlDigit)
+        }
+        result => result,
+    }
+}
+
+/// Check if the raw ASCII PESEL is valid, requiring exactly 11 digits, see
+/// [`validate_known_length`].
+///
+/// Returns the ASCII digits on success, without allocating a `String`.
+pub fn validate_bytes(bytes: &[u8]) -> Result<[u8; PESEL_LENGTH], ValidationError> {
+    let digits = std::str::from_utf8(bytes).map_err(|_| ValidationError::NonNumeric)?;
+    validate_known_length(digits)?;
+    Ok(bytes.try_into().unwrap())
+}
+
+/// Validate a PESEL string, returning it in its canonical zero-padded 11-digit form, e.g.
+/// `"00010128545"` for `"10128545"`.
+pub fn validate_and_normalize(s: &str) -> Result<String, ValidationError> {
+    human_redable::Pesel::parse(s).map(|pesel| pesel.full_string())
+}
+
+/// Summary of a PESEL string, see [`inspect`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PeselReport {
+    pub valid: bool,
+    /// All problems found, a wrong date of birth doesn't hide a wrong control digit.
+    pub errors: Vec<ValidationError>,
+    /// Details of the PESEL, `None` if it's invalid.
+    pub decomposed: Option<Decomposed>,
+}
+
+/// Validate a PESEL string, collecting all problems and its details into a single report.
+pub fn inspect(s: &str) -> PeselReport {
+    let mut errors = Vec::new();
+    let mut decomposed = None;
+    match human_redable::Pesel::parse(s) {
+        Ok(pesel) => decomposed = Some(pesel.decompose()),
+        Err(err @ (ValidationError::InvalidMonth | ValidationError::InvalidDay)) => {
+            errors.push(err);
+            if s.parse::<u64>()
+                .is_ok_and(|pesel| validate_control(pesel).is_err())
+            {
+                errors.push(ValidationError::ControlDigit);
+            }
+        }
+        Err(err) => errors.push(err),
+    }
+
+    PeselReport {
+        valid: errors.is_empty(),
+        errors,
+        decomposed,
+    }
+}
+
+/// Validate each of the PESELs, preserving their order.
+pub fn validate_many<I: IntoIterator<Item = u64>>(iter: I) -> Vec<Result<(), ValidationError>> {
+    iter.into_iter().map(validate).collect()
+}
+
+/// Validate a column of PESEL strings, returning the zero-based indices of the failing rows
+/// along with their errors.
+pub fn validate_column<'a, I: IntoIterator<Item = &'a str>>(
+    iter: I,
+) -> Vec<(usize, ValidationError)> {
+    iter.into_iter()
+        .enumerate()
+        .filter_map(|(row, pesel)| {
+            human_redable::Pesel::parse(pesel)
+                .err()
+                .map(|err| (row, err))
+        })
+        .collect()
+}
+
+/// Normalize a column of PESEL strings, preserving their order, see [`validate_and_normalize`].
+pub fn normalize_column<'a, I: IntoIterator<Item = &'a str>>(
+    iter: I,
+) -> Vec<Result<String, ValidationError>> {
+    iter.into_iter().map(validate_and_normalize).collect()
+}
+
+/// Validate each of the PESELs in parallel, preserving their order.
+#[cfg(feature = "rayon")]
+pub fn validate_many_par<I>(iter: I) -> Vec<Result<(), ValidationError>>
+where
+    I: rayon::iter::IntoParallelIterator<Item = u64>,
+{
+    use rayon::iter::ParallelIterator;
+
+    iter.into_par_iter().map(validate).collect()
+}
+
+/// Group PESELs by their year of birth, preserving their order within each year.
+pub fn group_by_year<T: PeselTrait>(pesels: Vec<T>) -> std::collections::BTreeMap<u16, Vec<T>>
+where
+    u64: From<T>,
+    for<'a> u64: From<&'a T>,
+{
+    let mut groups = std::collections::BTreeMap::<u16, Vec<T>>::new();
+    for pesel in pesels {
+        groups.entry(pesel.year()).or_default().push(pesel);
+    }
+
+    groups
+}
+
+/// Count PESELs per decade of birth, see [`PeselTrait::birth_decade`].
+pub fn group_by_decade<T: PeselTrait>(pesels: &[T]) -> std::collections::BTreeMap<u16, usize>
+where
+    u64: From<T>,
+    for<'a> u64: From<&'a T>,
+{
+    let mut counts = std::collections::BTreeMap::new();
+    for pesel in pesels {
+        *counts.entry(pesel.birth_decade()).or_default() += 1;
+    }
+
+    counts
+}
+
+/// Split PESELs into `(males, females)` in a single pass, preserving their order.
+pub fn partition_by_gender<T: PeselTrait>(pesels: &[T]) -> (Vec<&T>, Vec<&T>)
+where
+    u64: From<T>,
+    for<'a> u64: From<&'a T>,
+{
+    pesels
+        .iter()
+        .partition(|pesel| pesel.gender() == Gender::Male)
+}
+
+/// Maximum number of `?` wildcards accepted by [`complete_masked`].
+pub const MAX_MASKED_DIGITS: usize = 3;
+
+/// Find all valid PESELs matching a masked `pattern`.
+///
+/// The `pattern` must be 11 characters long, each being either a digit or a `?` wildcard standing
+/// for an unknown digit, e.g. `"0229048?168"`.
+///
+/// # Errors
+/// Returns an empty [`Vec`] if:
+/// - `pattern` is not 11 characters long
+/// - `pattern` contains characters other than digits and `?`
+/// - `pattern` contains more than [`MAX_MASKED_DIGITS`] wildcards
+pub fn complete_masked(pattern: &str) -> Vec<human_redable::Pesel> {
+    let pattern = pattern.as_bytes();
+    if pattern.len() != 11 || !pattern.iter().all(|c| c.is_ascii_digit() || *c == b'?') {
+        return Vec::new();
+    }
+
+    let wildcards: Vec<usize> = (0..11).filter(|&i| pattern[i] == b'?').collect();
+    if wildcards.len() > MAX_MASKED_DIGITS {
+        return Vec::new();
+    }
+
+    let mut digits = [0u8; 11];
+    (0..10u64.pow(wildcards.len() as u32))
+        .filter_map(|mut substitution| {
+            for (i, c) in pattern.iter().enumerate() {
+                digits[i] = if *c == b'?' { 0 } else { c - b'0' };
+            }
+            for i in wildcards.iter().rev() {
+                digits[*i] = (substitution % 10) as u8;
+                substitution /= 10;
+            }
+
+            let pesel = digits
+                .iter()
+                .fold(0u64, |acc, digit| acc * 10 + *digit as u64);
+            human_redable::Pesel::try_from(pesel).ok()
+        })
+        .collect()
+}
+
+/// All valid PESELs with the given date of birth, one per serial number, see
+/// [`SERIALS_PER_DATE`].
+///
+/// Genders alternate between consecutive serial numbers, starting with female. Yields nothing if
+/// `date` is not between `1800-01-01` and `2299-12-31`.
+#[cfg(feature = "chrono")]
+pub fn pesels_for_date(date: NaiveDate) -> impl Iterator<Item = human_redable::Pesel> {
+    (0..SERIALS_PER_DATE).filter_map(move |serial| {
+        human_redable::Pesel::try_from_date_and_serial(date, serial * 10 + serial % 2).ok()
+    })
+}
+
+/// All valid PESELs with a date of birth between `start` and `end` (inclusive), see
+/// [`pesels_for_date`].
+///
+/// Dates before `1800-01-01` or after `2299-12-31` are skipped.
+#[cfg(feature = "chrono")]
+pub fn pesels_in_range(
+    start: NaiveDate,
+    end: NaiveDate,
+) -> impl Iterator<Item = human_redable::Pesel> {
+    let start = start.max(NaiveDate::from_ymd_opt(1800, 1, 1).unwrap());
+    let end = end.min(NaiveDate::from_ymd_opt(2299, 12, 31).unwrap());
+
+    start
+        .iter_days()
+        .take_while(move |date| *date <= end)
+        .flat_map(pesels_for_date)
+}
+
+/// Number of valid PESELs with a date of birth between `start` and `end` (inclusive), without
+/// enumerating them, see [`pesels_in_range`].
+#[cfg(feature = "chrono")]
+pub fn valid_count_in_range(start: NaiveDate, end: NaiveDate) -> u64 {
+    let start = start.max(NaiveDate::from_ymd_opt(1800, 1, 1).unwrap());
+    let end = end.min(NaiveDate::from_ymd_opt(2299, 12, 31).unwrap());
+    if start > end {
+        return 0;
+    }
+
+    ((end - start).num_days() as u64 + 1) * SERIALS_PER_DATE as u64
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+#[error("{0}")]
+pub enum PeselTryFromError<T> {
+    ValidationError(#[from] ValidationError),
+    Other(T),
+}
+
+#[macro_export]
+macro_rules! impl_try_from_str_for_pesel {
+    ($name:ident) => {
+        impl TryFrom<&str> for $name {
+            type Error = PeselTryFromError<std::num::ParseIntError>;
+
+            fn try_from(value: &str) -> Result<Self, Self::Error> {
+                // `u64::from_str` accepts a leading `+`, only plain digits are allowed.
+                if !value.bytes().all(|byte| byte.is_ascii_digit()) {
+                    return Err(ValidationError::NonNumeric.into());
+                }
+
+                validate_length(value.len())?;
+
+                let value = value.parse::<u64>().map_err(PeselTryFromError::Other)?;
+                validate(value)?;
+                Self::try_from(value).map_err(PeselTryFromError::ValidationError)
+            }
+        }
 
         impl TryFrom<&String> for $name {
             type Error = PeselTryFromError<std::num::ParseIntError>;
@@ -432,10 +1545,27 @@ macro_rules! impl_try_from_str_for_pesel {
                 Self::try_from(&value)
             }
         }
+
+        impl TryFrom<std::borrow::Cow<'_, str>> for $name {
+            type Error = PeselTryFromError<std::num::ParseIntError>;
+
+            fn try_from(value: std::borrow::Cow<'_, str>) -> Result<Self, Self::Error> {
+                Self::try_from(value.as_ref())
+            }
+        }
+
+        impl std::str::FromStr for $name {
+            type Err = PeselTryFromError<std::num::ParseIntError>;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                Self::try_from(s)
+            }
+        }
     };
 }
 
 #[cfg(test)]
+#[allow(clippy::zero_prefixed_literal)]
 mod tests {
     use super::*;
 
@@ -525,6 +1655,7 @@ mod tests {
     }
 
     #[test]
+    #[cfg(feature = "chrono")]
     fn date_of_birth() {
         assert_eq!(
             super::date_of_birth(PESEL1),
@@ -557,6 +1688,194 @@ mod tests {
         assert_eq!(super::gender(PESEL5), Gender::Male);
     }
 
+    #[test]
+    fn gender_from_str() {
+        for s in ["M", "m", "Male", "male", "MALE"] {
+            assert_eq!(s.parse(), Ok(Gender::Male));
+        }
+        for s in ["K", "k", "Female", "female", "FEMALE"] {
+            assert_eq!(s.parse(), Ok(Gender::Female));
+        }
+        assert_eq!("F".parse::<Gender>(), Err(gender::GenderParseError));
+        assert_eq!(" male".parse::<Gender>(), Err(gender::GenderParseError));
+    }
+
+    #[test]
+    fn gender_conversions() {
+        for gender in [Gender::Male, Gender::Female] {
+            assert_eq!(Gender::from(bool::from(gender.clone())), gender);
+            assert_eq!(Gender::try_from(char::from(gender.clone())), Ok(gender));
+        }
+        assert!(bool::from(Gender::Male));
+        assert!(!bool::from(Gender::Female));
+        assert_eq!(char::from(Gender::Male), 'M');
+        assert_eq!(char::from(Gender::Female), 'K');
+        assert_eq!(Gender::try_from('k'), Ok(Gender::Female));
+        assert_eq!(Gender::try_from('F'), Err(gender::GenderParseError));
+    }
+
+    #[test]
+    #[cfg(feature = "chrono")]
+    fn gender_ord() {
+        let mut genders = vec![Gender::Male, Gender::Female, Gender::Male, Gender::Female];
+        genders.sort();
+        assert_eq!(
+            genders,
+            [Gender::Female, Gender::Female, Gender::Male, Gender::Male]
+        );
+        // The declaration order is unchanged, it determines the discriminants and serde indices.
+        assert_eq!(Gender::Male as u8, 0);
+        assert_eq!(Gender::Female as u8, 1);
+
+        let mut pesels = [PESEL5, PESEL4, PESEL2, PESEL1, PESEL3]
+            .map(|pesel| human_redable::Pesel::try_from(pesel).unwrap());
+        pesels.sort_by_key(|pesel| (pesel.gender(), pesel.date_of_birth()));
+        assert_eq!(
+            pesels.map(u64::from),
+            [PESEL3, PESEL1, PESEL4, PESEL5, PESEL2]
+        );
+    }
+
+    #[test]
+    fn to_digits() {
+        assert_eq!(super::to_digits(PESEL1), [0, 2, 2, 9, 0, 4, 8, 6, 1, 6, 8]);
+        assert_eq!(super::to_digits(PESEL3), [0, 0, 0, 1, 0, 1, 2, 8, 5, 4, 5]);
+        assert_eq!(super::to_digits(PESEL5), [6, 0, 0, 3, 2, 4, 1, 7, 8, 7, 4]);
+    }
+
+    #[test]
+    fn has_unknown_date_components() {
+        assert!(super::has_unknown_date_components(02290086168u64));
+        assert!(super::has_unknown_date_components(02000486168u64));
+        assert!(super::has_unknown_date_components(02400486168u64));
+        assert!(!super::has_unknown_date_components(PESEL1));
+        assert!(!super::has_unknown_date_components(PESEL3));
+    }
+
+    #[test]
+    fn canonical() {
+        use std::collections::HashSet;
+
+        enum AnyPesel {
+            BitFields(bit_fields::Pesel),
+            HumanRedable(human_redable::Pesel),
+        }
+
+        impl TryFrom<u64> for AnyPesel {
+            type Error = ValidationError;
+
+            fn try_from(value: u64) -> Result<Self, Self::Error> {
+                human_redable::Pesel::try_from(value).map(Self::HumanRedable)
+            }
+        }
+
+        impl From<AnyPesel> for u64 {
+            fn from(value: AnyPesel) -> Self {
+                (&value).into()
+            }
+        }
+
+        impl From<&AnyPesel> for u64 {
+            fn from(value: &AnyPesel) -> Self {
+                match value {
+                    AnyPesel::BitFields(pesel) => pesel.into(),
+                    AnyPesel::HumanRedable(pesel) => pesel.into(),
+                }
+            }
+        }
+
+        impl PeselTrait for AnyPesel {}
+
+        impl PeselSections for AnyPesel {
+            fn day_section(&self) -> u8 {
+                super::day_section(self)
+            }
+
+            fn month_section(&self) -> u8 {
+                super::month_section(self)
+            }
+
+            fn year_section(&self) -> u8 {
+                super::year_section(self)
+            }
+
+            fn ordinal_section(&self) -> u16 {
+                super::ordinal_section(self)
+            }
+
+            fn control_section(&self) -> u8 {
+                super::control_section(self)
+            }
+        }
+
+        let mut set = HashSet::new();
+        set.insert(Canonical(AnyPesel::BitFields(
+            bit_fields::Pesel::try_from(PESEL1).unwrap(),
+        )));
+        set.insert(Canonical(AnyPesel::HumanRedable(
+            human_redable::Pesel::try_from(PESEL1).unwrap(),
+        )));
+        assert_eq!(set.len(), 1);
+
+        assert!(
+            Canonical(bit_fields::Pesel::try_from(PESEL2).unwrap())
+                == Canonical(human_redable::Pesel::try_from(PESEL2).unwrap())
+        );
+        assert!(
+            Canonical(bit_fields::Pesel::try_from(PESEL2).unwrap())
+                != Canonical(human_redable::Pesel::try_from(PESEL3).unwrap())
+        );
+    }
+
+    #[test]
+    fn cmp_canonical() {
+        use std::{cmp::Ordering, collections::BTreeMap};
+
+        let pesels = [PESEL1, PESEL2, PESEL3, PESEL4, PESEL5];
+        let map: BTreeMap<_, _> = pesels
+            .iter()
+            .map(|&pesel| {
+                (
+                    Canonical(bit_fields::Pesel::try_from(pesel).unwrap()),
+                    pesel,
+                )
+            })
+            .collect();
+
+        let mut sorted = pesels;
+        sorted.sort();
+        assert_eq!(map.into_values().collect::<Vec<_>>(), sorted);
+
+        assert_eq!(
+            bit_fields::Pesel::try_from(PESEL3)
+                .unwrap()
+                .cmp_canonical(&human_redable::Pesel::try_from(PESEL2).unwrap()),
+            Ordering::Less
+        );
+        assert_eq!(
+            bit_fields::Pesel::try_from(PESEL1)
+                .unwrap()
+                .cmp_canonical(&human_redable::Pesel::try_from(PESEL1).unwrap()),
+            Ordering::Equal
+        );
+    }
+
+    #[test]
+    fn to_u64_dyn() {
+        let pesels: Vec<Box<dyn PeselSections>> = vec![
+            Box::new(bit_fields::Pesel::try_from(PESEL1).unwrap()),
+            Box::new(human_redable::Pesel::try_from(PESEL3).unwrap()),
+            Box::new(bit_fields::Pesel::try_from(PESEL4).unwrap()),
+        ];
+        assert_eq!(
+            pesels
+                .iter()
+                .map(|pesel| super::to_u64_dyn(pesel.as_ref()))
+                .collect::<Vec<_>>(),
+            [PESEL1, PESEL3, PESEL4]
+        );
+    }
+
     #[test]
     fn validate() {
         assert_eq!(super::validate(PESEL1), Ok(()));
@@ -566,8 +1885,731 @@ mod tests {
         assert_eq!(super::validate(PESEL5), Ok(()));
     }
 
+    #[test]
+    fn validate_large_checksum_sum() {
+        // 9*1 + 9*3 + 9*7 + 2*9 + 3*1 + 1*3 + 9*7 + 9*9 + 9*1 + 9*3 + 7*1 = 310
+        assert_eq!(super::validate(99923199997u64), Ok(()));
+    }
+
+    #[test]
+    fn validate_length() {
+        assert_eq!(super::validate_length(0), Err(ValidationError::TooShort(0)));
+        assert_eq!(super::validate_length(7), Err(ValidationError::TooShort(7)));
+        assert_eq!(super::validate_length(8), Ok(()));
+        assert_eq!(super::validate_length(11), Ok(()));
+        assert_eq!(
+            super::validate_length(12),
+            Err(ValidationError::TooLong(12))
+        );
+
+        assert_eq!(
+            super::validate(1234567u64),
+            Err(ValidationError::TooShort(7))
+        );
+        assert_eq!(
+            super::validate(100000000000u64),
+            Err(ValidationError::TooLong(12))
+        );
+        assert_eq!(
+            human_redable::Pesel::try_from(""),
+            Err(ValidationError::TooShort(0).into())
+        );
+        assert_eq!(
+            bit_fields::Pesel::try_from("1234567"),
+            Err(ValidationError::TooShort(7).into())
+        );
+    }
+
+    #[test]
+    fn validate_repeated_digits() {
+        assert_eq!(
+            super::validate(00000000000u64),
+            Err(ValidationError::TooShort(1))
+        );
+        assert_eq!(
+            super::validate_known_length("00000000000"),
+            Err(ValidationError::RepeatedDigits)
+        );
+        for digit in 1..=9 {
+            assert_eq!(
+                super::validate(digit * 11111111111u64),
+                Err(ValidationError::RepeatedDigits)
+            );
+        }
+        assert!(human_redable::Pesel::try_from("22222222222").is_err());
+    }
+
+    #[test]
+    fn year_from_sections() {
+        for (month_section, century) in [
+            (01..=12, 1900),
+            (21..=32, 2000),
+            (41..=52, 2100),
+            (61..=72, 2200),
+            (81..=92, 1800),
+        ] {
+            for month_section in month_section {
+                assert_eq!(super::year_from_sections(month_section, 00), century);
+                assert_eq!(super::year_from_sections(month_section, 99), century + 99);
+            }
+        }
+    }
+
+    #[test]
+    fn century_from_section() {
+        assert_eq!(super::century_from_section(81), 1800);
+        assert_eq!(super::century_from_section(92), 1800);
+        assert_eq!(super::century_from_section(01), 1900);
+        assert_eq!(super::century_from_section(12), 1900);
+        assert_eq!(super::century_from_section(21), 2000);
+        assert_eq!(super::century_from_section(32), 2000);
+        assert_eq!(super::century_from_section(41), 2100);
+        assert_eq!(super::century_from_section(52), 2100);
+        assert_eq!(super::century_from_section(61), 2200);
+        assert_eq!(super::century_from_section(72), 2200);
+
+        assert_eq!(
+            human_redable::Pesel::try_from(PESEL1)
+                .unwrap()
+                .century_from_shift(),
+            2000
+        );
+        assert_eq!(
+            bit_fields::Pesel::try_from(PESEL5)
+                .unwrap()
+                .century_from_shift(),
+            1900
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "chrono")]
+    fn days_in_month() {
+        for year in 1800..=2299 {
+            for month in 1..=12 {
+                let first = NaiveDate::from_ymd_opt(year as i32, month as u32, 1).unwrap();
+                let next = first.checked_add_months(chrono::Months::new(1)).unwrap();
+                assert_eq!(
+                    super::days_in_month(year, month) as i64,
+                    (next - first).num_days()
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn month_to_section() {
+        assert_eq!(super::month_to_section(9, 1899), Some(89));
+        assert_eq!(super::month_to_section(9, 1999), Some(09));
+        assert_eq!(super::month_to_section(9, 2002), Some(29));
+        assert_eq!(super::month_to_section(9, 2150), Some(49));
+        assert_eq!(super::month_to_section(12, 2299), Some(72));
+        assert_eq!(super::month_to_section(13, 2002), None);
+        assert_eq!(super::month_to_section(9, 2300), None);
+
+        for (year, month_section) in [
+            (1799, None),
+            (1800, Some(81)),
+            (1899, Some(81)),
+            (1900, Some(01)),
+            (1999, Some(01)),
+            (2000, Some(21)),
+            (2099, Some(21)),
+            (2100, Some(41)),
+            (2199, Some(41)),
+            (2200, Some(61)),
+            (2299, Some(61)),
+            (2300, None),
+        ] {
+            assert_eq!(super::month_to_section(1, year), month_section);
+        }
+    }
+
+    #[test]
+    fn control_digit_for() {
+        assert_eq!(super::control_digit_for(1960, 03, 24, 1787), Some(4));
+        assert_eq!(super::control_digit_for(2002, 09, 04, 8616), Some(8));
+        assert_eq!(super::control_digit_for(2002, 09, 104, 8616), None);
+        assert_eq!(super::control_digit_for(1900, 01, 01, 2854), Some(5));
+        assert_eq!(super::control_digit_for(2002, 02, 30, 8616), None);
+        assert_eq!(super::control_digit_for(2002, 13, 04, 8616), None);
+        assert_eq!(super::control_digit_for(2300, 09, 04, 8616), None);
+        assert_eq!(super::control_digit_for(2002, 09, 04, 10000), None);
+    }
+
+    #[test]
+    fn compute_control_digit() {
+        assert_eq!(super::compute_control_digit(PESEL1 / 10), 8);
+        assert_eq!(super::compute_control_digit(PESEL2 / 10), 9);
+        assert_eq!(super::compute_control_digit(PESEL3 / 10), 5);
+        assert_eq!(super::compute_control_digit(PESEL4 / 10), 5);
+        assert_eq!(super::compute_control_digit(PESEL5 / 10), 4);
+    }
+
+    #[test]
+    fn control_digit_of_multiple_of_ten() {
+        // 0*1 + 2*3 + 2*7 + 9*9 + 0*1 + 4*3 + 0*7 + 0*9 + 0*1 + 9*3 = 140
+        assert_eq!(super::compute_control_digit(229040009), 0);
+        assert_eq!(super::append_control_digit(229040009), 02290400090);
+        assert_eq!(super::validate(02290400090u64), Ok(()));
+    }
+
+    #[test]
+    fn constants() {
+        assert_eq!(PESEL_WEIGHTS, [1, 3, 7, 9, 1, 3, 7, 9, 1, 3, 1]);
+        assert_eq!(PESEL_WEIGHTS.len(), PESEL_LENGTH);
+
+        let sections = [
+            (YEAR_SECTION_POSITION, YEAR_SECTION_DIGITS),
+            (MONTH_SECTION_POSITION, MONTH_SECTION_DIGITS),
+            (DAY_SECTION_POSITION, DAY_SECTION_DIGITS),
+            (ORDINAL_SECTION_POSITION, ORDINAL_SECTION_DIGITS),
+            (CONTROL_SECTION_POSITION, CONTROL_SECTION_DIGITS),
+        ];
+        let mut end = 0;
+        for (position, digits) in sections {
+            assert_eq!(position, end);
+            end += digits;
+        }
+        assert_eq!(end, PESEL_LENGTH);
+        assert_eq!(SERIAL_DIGITS, ORDINAL_SECTION_DIGITS - 1);
+
+        let pesel = human_redable::Pesel::try_from(PESEL1)
+            .unwrap()
+            .full_string();
+        assert_eq!(
+            &pesel[ORDINAL_SECTION_POSITION..][..ORDINAL_SECTION_DIGITS],
+            "8616"
+        );
+    }
+
+    #[test]
+    fn checksum_breakdown() {
+        let breakdown = super::checksum_breakdown(PESEL1);
+        assert_eq!(breakdown[3], (9, 9, 81));
+        assert_eq!(breakdown[10], (8, 1, 8));
+        assert_eq!(
+            breakdown.iter().map(|(_, _, product)| product).sum::<u16>() % 10,
+            0
+        );
+    }
+
+    #[test]
+    fn append_control_digit() {
+        assert_eq!(super::append_control_digit(6003241787), 60032417874);
+        assert_eq!(super::append_control_digit(PESEL3 / 10), PESEL3);
+        assert_eq!(
+            super::validate(super::append_control_digit(9999048616)),
+            Err(ValidationError::InvalidMonth)
+        );
+    }
+
+    #[test]
+    fn complete_masked() {
+        let pesels = super::complete_masked("0229048?168");
+        assert!(pesels.contains(&human_redable::Pesel::try_from(PESEL1).unwrap()));
+        for pesel in pesels {
+            assert_eq!(super::validate(&pesel), Ok(()));
+            assert_eq!(u64::from(&pesel) / 10_000, PESEL1 / 10_000);
+            assert_eq!(u64::from(&pesel) % 1000, PESEL1 % 1000);
+        }
+
+        assert!(super::complete_masked("02290486168").len() == 1);
+        assert!(super::complete_masked("0229048616").is_empty());
+        assert!(super::complete_masked("02290x86168").is_empty());
+        assert!(super::complete_masked("????0486168").is_empty());
+    }
+
+    #[test]
+    fn group_by_year() {
+        let pesels = [PESEL1, PESEL2, PESEL3, PESEL4, PESEL5, 02290486113]
+            .map(|pesel| human_redable::Pesel::try_from(pesel).unwrap());
+        let groups = super::group_by_year(pesels.to_vec());
+
+        assert_eq!(
+            groups.keys().copied().collect::<Vec<_>>(),
+            [1900, 1960, 2001, 2002, 2098]
+        );
+        assert_eq!(groups[&2002], [pesels[0].clone(), pesels[5].clone()]);
+        assert_eq!(groups[&2001], [pesels[1].clone()]);
+        assert_eq!(groups[&1900], [pesels[2].clone()]);
+        assert_eq!(groups[&2098], [pesels[3].clone()]);
+        assert_eq!(groups[&1960], [pesels[4].clone()]);
+    }
+
+    #[test]
+    fn group_by_decade() {
+        let pesels = [PESEL1, PESEL2, PESEL3, PESEL4, PESEL5]
+            .map(|pesel| bit_fields::Pesel::try_from(pesel).unwrap());
+        assert_eq!(pesels[0].birth_decade(), 2000);
+        assert_eq!(pesels[3].birth_decade(), 2090);
+
+        assert_eq!(
+            super::group_by_decade(&pesels)
+                .into_iter()
+                .collect::<Vec<_>>(),
+            [(1900, 1), (1960, 1), (2000, 2), (2090, 1)]
+        );
+    }
+
+    #[test]
+    fn partition_by_gender() {
+        let pesels = [PESEL1, PESEL2, PESEL3, PESEL4, PESEL5]
+            .map(|pesel| bit_fields::Pesel::try_from(pesel).unwrap());
+        let (males, females) = super::partition_by_gender(&pesels);
+
+        assert_eq!(males.len(), 2);
+        assert_eq!(females.len(), 3);
+        assert_eq!(males, [&pesels[1], &pesels[4]]);
+        assert_eq!(females, [&pesels[0], &pesels[2], &pesels[3]]);
+    }
+
+    /// Define a test running `$body` once for each layout, with `Pesel` naming the layout.
+    macro_rules! test_both_layouts {
+        ($(#[$attr:meta])* fn $name:ident() $body:block) => {
+            #[test]
+            $(#[$attr])*
+            fn $name() {
+                {
+                    use crate::bit_fields::Pesel;
+                    $body
+                }
+                {
+                    use crate::human_redable::Pesel;
+                    $body
+                }
+            }
+        };
+    }
+
+    test_both_layouts! {
+        fn try_from_signed_string() {
+            assert_eq!(
+                Pesel::try_from("+02290486168"),
+                Err(ValidationError::NonNumeric.into())
+            );
+            assert_eq!(
+                Pesel::try_from("-02290486168"),
+                Err(ValidationError::NonNumeric.into())
+            );
+            assert_eq!(
+                Pesel::try_from(" 02290486168"),
+                Err(ValidationError::NonNumeric.into())
+            );
+        }
+    }
+
+    test_both_layouts! {
+        fn try_from_long_string() {
+            assert_eq!(
+                Pesel::try_from("435585930294485"),
+                Err(ValidationError::TooLong(15).into())
+            );
+            assert_eq!(
+                Pesel::try_from("435585930294485435585930294485"),
+                Err(ValidationError::TooLong(30).into())
+            );
+        }
+    }
+
+    test_both_layouts! {
+        fn try_from_cow() {
+            use std::borrow::Cow;
+
+            assert_eq!(
+                Pesel::try_from(PESEL1).unwrap(),
+                Pesel::try_from(Cow::Borrowed("02290486168")).unwrap()
+            );
+            assert_eq!(
+                Pesel::try_from(PESEL2).unwrap(),
+                Pesel::try_from(Cow::<str>::Owned(String::from("01302534699"))).unwrap()
+            );
+            Pesel::try_from(Cow::Borrowed("02290486167")).expect_err("Invalid PESEL");
+        }
+    }
+
+    test_both_layouts! {
+        fn full_string() {
+            assert_eq!(Pesel::try_from(PESEL3).unwrap().full_string(), "00010128545");
+            assert_eq!(Pesel::try_from(PESEL4).unwrap().full_string(), "98250993285");
+        }
+    }
+
+    test_both_layouts! {
+        fn write_to() {
+            let mut buf = [0; 11];
+            Pesel::try_from(PESEL3).unwrap().write_to(&mut buf);
+            assert_eq!(&buf, b"00010128545");
+            Pesel::try_from(PESEL1).unwrap().write_to(&mut buf);
+            assert_eq!(&buf, b"02290486168");
+        }
+    }
+
+    test_both_layouts! {
+        #[cfg(feature = "chrono")]
+        fn compare_with_date() {
+            let pesel1 = Pesel::try_from(PESEL1).unwrap();
+            let pesel3 = Pesel::try_from(PESEL3).unwrap();
+            assert!(pesel3 == NaiveDate::from_ymd_opt(1900, 01, 01).unwrap());
+            assert!(pesel3 < NaiveDate::from_ymd_opt(1950, 01, 01).unwrap());
+            assert!(pesel1 > NaiveDate::from_ymd_opt(1950, 01, 01).unwrap());
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "chrono")]
+    fn pesels_for_date() {
+        let date = NaiveDate::from_ymd_opt(2002, 09, 04).unwrap();
+        let pesels: Vec<_> = super::pesels_for_date(date).collect();
+        assert_eq!(pesels.len(), SERIALS_PER_DATE as usize);
+        assert_eq!(
+            pesels[861],
+            human_redable::Pesel::try_from(02290486113).unwrap()
+        );
+        assert_eq!(pesels[0].gender(), Gender::Female);
+        assert_eq!(pesels[1].gender(), Gender::Male);
+
+        let date = NaiveDate::from_ymd_opt(2300, 01, 01).unwrap();
+        assert_eq!(super::pesels_for_date(date).count(), 0);
+    }
+
+    #[test]
+    #[cfg(feature = "chrono")]
+    fn valid_count_in_range() {
+        let date = |year, month, day| NaiveDate::from_ymd_opt(year, month, day).unwrap();
+
+        assert_eq!(
+            super::valid_count_in_range(date(2002, 09, 01), date(2002, 09, 07)),
+            7000
+        );
+        assert_eq!(
+            super::valid_count_in_range(date(2002, 09, 04), date(2002, 09, 05)),
+            super::pesels_in_range(date(2002, 09, 04), date(2002, 09, 05)).count() as u64
+        );
+        assert_eq!(
+            super::valid_count_in_range(date(2299, 12, 31), date(2300, 01, 05)),
+            1000
+        );
+        assert_eq!(
+            super::valid_count_in_range(date(2002, 09, 05), date(2002, 09, 04)),
+            0
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "chrono")]
+    fn pesels_in_range() {
+        let start = NaiveDate::from_ymd_opt(2002, 09, 04).unwrap();
+        let end = NaiveDate::from_ymd_opt(2002, 09, 05).unwrap();
+        assert_eq!(super::pesels_in_range(start, end).count(), 2000);
+        assert_eq!(super::pesels_in_range(end, start).count(), 0);
+
+        let start = NaiveDate::from_ymd_opt(2299, 12, 31).unwrap();
+        let end = NaiveDate::from_ymd_opt(2300, 01, 05).unwrap();
+        assert_eq!(super::pesels_in_range(start, end).count(), 1000);
+    }
+
+    #[test]
+    fn user_message() {
+        assert_eq!(
+            ValidationError::TooShort(4).user_message(),
+            "Pesel is too short."
+        );
+        assert_eq!(
+            ValidationError::TooLong(15).user_message(),
+            "Pesel is too long."
+        );
+        #[allow(deprecated)]
+        let birth_date = ValidationError::BirthDate;
+        assert_eq!(
+            birth_date.user_message(),
+            "Pesel has an invalid date of birth."
+        );
+        assert_eq!(
+            ValidationError::InvalidMonth.user_message(),
+            "Pesel has an invalid month of birth."
+        );
+        assert_eq!(
+            ValidationError::InvalidDay.user_message(),
+            "Pesel has an invalid day of birth."
+        );
+        assert_eq!(
+            ValidationError::ControlDigit.user_message(),
+            "Pesel has an invalid control digit."
+        );
+        assert_eq!(
+            ValidationError::Ordinal.user_message(),
+            "Pesel has an invalid ordinal number."
+        );
+        assert_eq!(
+            ValidationError::NonNumeric.user_message(),
+            "Pesel contains non-numeric characters."
+        );
+        assert_eq!(
+            ValidationError::RepeatedDigits.user_message(),
+            "Pesel consists of a single repeated digit."
+        );
+    }
+
+    #[test]
+    fn validate_control() {
+        assert_eq!(super::validate_control(PESEL1), Ok(()));
+        assert_eq!(super::validate_control(PESEL3), Ok(()));
+        assert_eq!(super::validate_control(02290486167u64), Err((8, 7)));
+    }
+
+    #[test]
+    fn validate_known_length() {
+        assert_eq!(super::validate_known_length("00010128545"), Ok(()));
+        assert_eq!(
+            super::validate_known_length("10128545"),
+            Err(ValidationError::TooShort(8))
+        );
+        assert_eq!(
+            super::validate_known_length("000010128545"),
+            Err(ValidationError::TooLong(12))
+        );
+        assert_eq!(
+            super::validate_known_length("0001012854x"),
+            Err(ValidationError::NonNumeric)
+        );
+        assert_eq!(
+            super::validate_known_length("00010128546"),
+            Err(ValidationError::ControlDigit)
+        );
+        assert_eq!(
+            super::validate_known_length("00000128544"),
+            Err(ValidationError::InvalidMonth)
+        );
+    }
+
+    #[test]
+    fn validate_checksum_and_length_only() {
+        assert_eq!(
+            super::validate_checksum_and_length_only("02290486168"),
+            Ok(())
+        );
+        assert_eq!(
+            super::validate_known_length("02223086160"),
+            Err(ValidationError::InvalidDay)
+        );
+        assert_eq!(
+            super::validate_checksum_and_length_only("02223086160"),
+            Ok(())
+        );
+        assert_eq!(
+            super::validate_checksum_and_length_only("02993086168"),
+            Ok(())
+        );
+        assert_eq!(
+            super::validate_checksum_and_length_only("00000128544"),
+            Ok(())
+        );
+        assert_eq!(
+            super::validate_checksum_and_length_only("02223086161"),
+            Err(ValidationError::ControlDigit)
+        );
+        assert_eq!(
+            super::validate_checksum_and_length_only("2223086160"),
+            Err(ValidationError::TooShort(10))
+        );
+        assert_eq!(
+            super::validate_checksum_and_length_only("0222308616x"),
+            Err(ValidationError::NonNumeric)
+        );
+        assert_eq!(
+            super::validate_checksum_and_length_only("00000000000"),
+            Err(ValidationError::RepeatedDigits)
+        );
+    }
+
+    #[test]
+    fn validate_allowing_temporary() {
+        assert_eq!(super::validate_allowing_temporary("02290486168"), Ok(()));
+        assert_eq!(super::validate_allowing_temporary("02290086160"), Ok(()));
+        assert_eq!(super::validate_allowing_temporary("02200486169"), Ok(()));
+        assert_eq!(super::validate_allowing_temporary("02000086165"), Ok(()));
+        assert_eq!(super::validate_allowing_temporary("02000128548"), Ok(()));
+        assert_eq!(super::validate_allowing_temporary("00000128544"), Ok(()));
+        assert_eq!(
+            super::validate_known_length("02290086160"),
+            Err(ValidationError::InvalidDay)
+        );
+        assert_eq!(
+            super::validate_allowing_temporary("02290086161"),
+            Err(ValidationError::ControlDigit)
+        );
+        assert_eq!(
+            super::validate_allowing_temporary("02993086168"),
+            Err(ValidationError::InvalidMonth)
+        );
+        assert_eq!(
+            super::validate_allowing_temporary("02293286161"),
+            Err(ValidationError::InvalidDay)
+        );
+        assert_eq!(
+            super::validate_allowing_temporary("02004586166"),
+            Err(ValidationError::InvalidDay)
+        );
+        assert_eq!(
+            super::validate_allowing_temporary("02930086165"),
+            Err(ValidationError::InvalidMonth)
+        );
+        assert_eq!(
+            super::validate_allowing_temporary("0229048616"),
+            Err(ValidationError::TooShort(10))
+        );
+    }
+
+    #[test]
+    fn validate_bytes() {
+        assert_eq!(super::validate_bytes(b"00010128545"), Ok(*b"00010128545"));
+        assert_eq!(super::validate_bytes(b"02290486168"), Ok(*b"02290486168"));
+        assert_eq!(
+            super::validate_bytes(b"10128545"),
+            Err(ValidationError::TooShort(8))
+        );
+        assert_eq!(
+            super::validate_bytes(b"000010128545"),
+            Err(ValidationError::TooLong(12))
+        );
+        assert_eq!(
+            super::validate_bytes(b"+0010128545"),
+            Err(ValidationError::NonNumeric)
+        );
+        assert_eq!(
+            super::validate_bytes(b"0229048616\0"),
+            Err(ValidationError::NonNumeric)
+        );
+        assert_eq!(
+            super::validate_bytes(b"00000000000"),
+            Err(ValidationError::RepeatedDigits)
+        );
+        assert_eq!(
+            super::validate_bytes(b"02223086160"),
+            Err(ValidationError::InvalidDay)
+        );
+        assert_eq!(
+            super::validate_bytes(b"00010128546"),
+            Err(ValidationError::ControlDigit)
+        );
+    }
+
+    #[test]
+    fn validate_and_normalize() {
+        assert_eq!(
+            super::validate_and_normalize("10128545"),
+            Ok("00010128545".to_string())
+        );
+        assert_eq!(
+            super::validate_and_normalize("02290486168"),
+            Ok("02290486168".to_string())
+        );
+        assert_eq!(
+            super::validate_and_normalize("0229048616x"),
+            Err(ValidationError::NonNumeric)
+        );
+        assert_eq!(
+            super::validate_and_normalize("02290486167"),
+            Err(ValidationError::ControlDigit)
+        );
+    }
+
+    #[test]
+    fn inspect() {
+        assert_eq!(
+            super::inspect("02290486168"),
+            PeselReport {
+                valid: true,
+                errors: Vec::new(),
+                decomposed: Some(human_redable::Pesel::try_from(PESEL1).unwrap().decompose()),
+            }
+        );
+        assert_eq!(
+            super::inspect("02223086161"),
+            PeselReport {
+                valid: false,
+                errors: vec![ValidationError::InvalidDay, ValidationError::ControlDigit],
+                decomposed: None,
+            }
+        );
+        assert_eq!(
+            super::inspect("02223086160"),
+            PeselReport {
+                valid: false,
+                errors: vec![ValidationError::InvalidDay],
+                decomposed: None,
+            }
+        );
+        assert_eq!(
+            super::inspect("0229048616x"),
+            PeselReport {
+                valid: false,
+                errors: vec![ValidationError::NonNumeric],
+                decomposed: None,
+            }
+        );
+    }
+
+    #[test]
+    fn validate_column() {
+        assert_eq!(
+            super::validate_column([
+                "02290486168",
+                "0229048616x",
+                "00010128545",
+                "98250993285",
+                "60032417875",
+            ]),
+            [
+                (1, ValidationError::NonNumeric),
+                (4, ValidationError::ControlDigit)
+            ]
+        );
+        assert_eq!(super::validate_column(["02290486168"]), []);
+    }
+
+    #[test]
+    fn normalize_column() {
+        assert_eq!(
+            super::normalize_column(["02290486168", "10128545", "0229048616x", "", "60032417875"]),
+            [
+                Ok("02290486168".to_string()),
+                Ok("00010128545".to_string()),
+                Err(ValidationError::NonNumeric),
+                Err(ValidationError::TooShort(0)),
+                Err(ValidationError::ControlDigit),
+            ]
+        );
+        assert_eq!(super::normalize_column([]), []);
+    }
+
+    #[test]
+    fn validate_many() {
+        assert_eq!(
+            super::validate_many([PESEL1, 4355, PESEL3, 02290486167]),
+            vec![
+                Ok(()),
+                Err(ValidationError::TooShort(4)),
+                Ok(()),
+                Err(ValidationError::ControlDigit)
+            ]
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "rayon")]
+    fn validate_many_par() {
+        let pesels: Vec<u64> = (0..10_000).map(|i| PESEL1 + i).collect();
+        assert_eq!(
+            super::validate_many_par(pesels.clone()),
+            super::validate_many(pesels)
+        );
+    }
+
     #[test]
     fn invalid_pesels() {
+        assert_eq!(super::validate(0u64), Err(ValidationError::TooShort(1)));
         assert_eq!(super::validate(4355u64), Err(ValidationError::TooShort(4)));
         assert_eq!(
             super::validate(435585930294485u64),
@@ -575,7 +2617,15 @@ mod tests {
         );
         assert_eq!(
             super::validate(99990486167u64),
-            Err(ValidationError::BirthDate)
+            Err(ValidationError::InvalidMonth)
+        );
+        assert_eq!(
+            super::validate(02130486160u64),
+            Err(ValidationError::InvalidMonth)
+        );
+        assert_eq!(
+            super::validate(02223086160u64),
+            Err(ValidationError::InvalidDay)
         );
         assert_eq!(
             super::validate(02290486167u64),
@@ -0,0 +1,165 @@
+//! Random PESEL generation, available with the `rand` feature.
+
+use super::*;
+use chrono::Datelike;
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+
+/// Generate a random valid PESEL with a date of birth between `1800-01-01` and `2299-12-31`.
+pub fn random<R: Rng + ?Sized>(rng: &mut R) -> human_redable::Pesel {
+    let first_day = NaiveDate::from_ymd_opt(1800, 1, 1).unwrap();
+    let last_day = NaiveDate::from_ymd_opt(2299, 12, 31).unwrap();
+
+    let date = random_date(rng, first_day, last_day);
+    random_on(rng, date)
+}
+
+fn random_date<R: Rng + ?Sized>(
+    rng: &mut R,
+    first_day: NaiveDate,
+    last_day: NaiveDate,
+) -> NaiveDate {
+    let days = first_day.num_days_from_ce()..=last_day.num_days_from_ce();
+    NaiveDate::from_num_days_from_ce_opt(rng.gen_range(days)).unwrap()
+}
+
+/// Generate a random valid PESEL with the given date of birth, `None` if `date` is not between
+/// `1800-01-01` and `2299-12-31`.
+pub fn random_with_date<R: Rng + ?Sized>(
+    date: NaiveDate,
+    rng: &mut R,
+) -> Option<human_redable::Pesel> {
+    loop {
+        match human_redable::Pesel::try_from_date_and_serial(date, rng.gen_range(0..=9999)) {
+            Ok(pesel) => return Some(pesel),
+            // Fails only for single repeated digit PESELs, e.g. `22222222222`.
+            Err(ValidationError::RepeatedDigits) => continue,
+            Err(_) => return None,
+        }
+    }
+}
+
+fn random_on<R: Rng + ?Sized>(rng: &mut R, date: NaiveDate) -> human_redable::Pesel {
+    match random_with_date(date, rng) {
+        Some(pesel) => pesel,
+        None => unreachable!(),
+    }
+}
+
+/// Generate a random valid PESEL of a person exactly `age` years old on `today`, see
+/// [`PeselTrait::age_at`].
+///
+/// February 29th is never picked as the date of birth, so the age doesn't depend on how leap
+/// years are treated.
+///
+/// # Panics
+/// If the date of birth would have to be before `1800-01-01` or after `2299-12-31`.
+#[cfg(feature = "clock")]
+pub fn generate_for_age<R: Rng + ?Sized>(
+    age: u32,
+    today: NaiveDate,
+    rng: &mut R,
+) -> human_redable::Pesel {
+    let years_ago = |years: u32| {
+        let months = years.checked_mul(12)?;
+        today.checked_sub_months(chrono::Months::new(months))
+    };
+
+    let last_day = years_ago(age);
+    let first_day = age
+        .checked_add(1)
+        .and_then(years_ago)
+        .and_then(|date| date.succ_opt());
+    let (Some(first_day), Some(last_day)) = (first_day, last_day) else {
+        panic!("Date of birth out of range.");
+    };
+    if first_day.year() < 1800 || last_day.year() > 2299 {
+        panic!("Date of birth out of range.");
+    }
+
+    loop {
+        let date = random_date(rng, first_day, last_day);
+        if (date.month(), date.day()) != (2, 29) {
+            return random_on(rng, date);
+        }
+    }
+}
+
+/// Generate `count` random valid PESELs from a `seed`.
+///
+/// The same seed always yields the same PESELs, regardless of the platform, which makes it
+/// suitable for reproducible test fixtures.
+pub fn generate_batch(seed: u64, count: usize) -> Vec<human_redable::Pesel> {
+    let mut rng = ChaCha8Rng::seed_from_u64(seed);
+    (0..count).map(|_| random(&mut rng)).collect()
+}
+
+#[cfg(test)]
+#[allow(clippy::zero_prefixed_literal)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn generate_batch() {
+        let batch = super::generate_batch(2137, 100);
+        assert_eq!(batch.len(), 100);
+        assert_eq!(batch, super::generate_batch(2137, 100));
+        assert_ne!(batch, super::generate_batch(2138, 100));
+
+        for pesel in batch {
+            assert_eq!(validate(pesel), Ok(()));
+        }
+    }
+
+    #[test]
+    fn random_with_date() {
+        let mut rng = ChaCha8Rng::seed_from_u64(2137);
+        let date = NaiveDate::from_ymd_opt(2002, 09, 04).unwrap();
+        for _ in 0..100 {
+            let pesel = super::random_with_date(date, &mut rng).unwrap();
+            assert_eq!(pesel.date_of_birth(), date);
+            assert_eq!(validate(pesel), Ok(()));
+        }
+
+        // `22222222222` is rejected as a placeholder, another serial has to be drawn.
+        let date = NaiveDate::from_ymd_opt(2022, 02, 22).unwrap();
+        for _ in 0..100_000 {
+            let pesel = super::random_with_date(date, &mut rng).unwrap();
+            assert_ne!(pesel.value(), 22222222222);
+        }
+
+        let date = NaiveDate::from_ymd_opt(2300, 01, 01).unwrap();
+        assert_eq!(super::random_with_date(date, &mut rng), None);
+    }
+
+    #[test]
+    #[cfg(feature = "clock")]
+    fn generate_for_age() {
+        let mut rng = ChaCha8Rng::seed_from_u64(2137);
+        for today in [
+            NaiveDate::from_ymd_opt(2024, 10, 14).unwrap(),
+            NaiveDate::from_ymd_opt(2024, 02, 29).unwrap(),
+            NaiveDate::from_ymd_opt(2025, 03, 01).unwrap(),
+        ] {
+            for age in [0, 1, 25, 100] {
+                for _ in 0..100 {
+                    let pesel = super::generate_for_age(age, today, &mut rng);
+                    assert_eq!(pesel.age_at(today), age);
+                    assert_ne!((pesel.month(), pesel.day()), (02, 29));
+                }
+            }
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "clock")]
+    #[should_panic(expected = "Date of birth out of range.")]
+    fn generate_for_age_out_of_range() {
+        let mut rng = ChaCha8Rng::seed_from_u64(2137);
+        super::generate_for_age(
+            u32::MAX,
+            NaiveDate::from_ymd_opt(2024, 10, 14).unwrap(),
+            &mut rng,
+        );
+    }
+}
@@ -0,0 +1,185 @@
+//! Collection of PESELs ordered by date of birth.
+
+use super::*;
+use std::collections::BTreeSet;
+
+/// Set of PESELs of any layout, ordered by date of birth.
+///
+/// PESELs are stored as `YYYYMMDDOOOOC` numbers, which unlike the human readable numbers sort
+/// by date of birth across centuries, making [`PeselSet::range_by_birth`] a range query.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PeselSet(BTreeSet<u64>);
+
+impl PeselSet {
+    /// Create an empty set.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Insert a PESEL, returning `false` if it was already present.
+    pub fn insert<T: PeselTrait>(&mut self, pesel: &T) -> bool
+    where
+        u64: From<T>,
+        for<'a> u64: From<&'a T>,
+    {
+        self.0.insert(sort_key(pesel))
+    }
+
+    /// Check if the PESEL is present, regardless of the layout it was inserted with.
+    pub fn contains<T: PeselTrait>(&self, pesel: &T) -> bool
+    where
+        u64: From<T>,
+        for<'a> u64: From<&'a T>,
+    {
+        self.0.contains(&sort_key(pesel))
+    }
+
+    /// Number of PESELs in the set.
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Check if the set contains no PESELs.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// PESELs born between `start` and `end` (inclusive), ordered by date of birth.
+    #[cfg(feature = "chrono")]
+    pub fn range_by_birth(
+        &self,
+        start: NaiveDate,
+        end: NaiveDate,
+    ) -> impl Iterator<Item = human_redable::Pesel> + '_ {
+        let end = date_key(end) + 100_000;
+        let start = date_key(start).min(end);
+
+        self.0.range(start..end).map(|&key| from_sort_key(key))
+    }
+}
+
+impl<T: PeselTrait> FromIterator<T> for PeselSet
+where
+    u64: From<T>,
+    for<'a> u64: From<&'a T>,
+{
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut set = Self::new();
+        set.extend(iter);
+        set
+    }
+}
+
+impl<T: PeselTrait> Extend<T> for PeselSet
+where
+    u64: From<T>,
+    for<'a> u64: From<&'a T>,
+{
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        self.0
+            .extend(iter.into_iter().map(|pesel| sort_key(&pesel)));
+    }
+}
+
+#[cfg(feature = "chrono")]
+fn date_key(date: NaiveDate) -> u64 {
+    use chrono::Datelike;
+
+    date.year().max(0) as u64 * 1_000_000_000
+        + date.month() as u64 * 10_000_000
+        + date.day() as u64 * 100_000
+}
+
+fn sort_key<T: PeselTrait>(pesel: &T) -> u64
+where
+    u64: From<T>,
+    for<'a> u64: From<&'a T>,
+{
+    pesel.year() as u64 * 1_000_000_000
+        + pesel.month() as u64 * 10_000_000
+        + pesel.normalized_u64() % 10_000_000
+}
+
+#[cfg(feature = "chrono")]
+fn from_sort_key(key: u64) -> human_redable::Pesel {
+    let year = (key / 1_000_000_000) as u16;
+    let month = ((key / 10_000_000) % 100) as u8;
+    let month_section = match month_to_section(month, year) {
+        Some(month_section) => month_section,
+        None => unreachable!(),
+    };
+
+    human_redable::Pesel::new_unchecked(
+        (year % 100) as u64 * 1_000_000_000 + month_section as u64 * 10_000_000 + key % 10_000_000,
+    )
+}
+
+#[cfg(test)]
+#[allow(clippy::zero_prefixed_literal)]
+mod tests {
+    use super::*;
+
+    static PESELS: [u64; 5] = [
+        02290486168,
+        01302534699,
+        00010128545,
+        98250993285,
+        60032417874,
+    ];
+
+    #[test]
+    fn insert_contains() {
+        let mut set = PeselSet::new();
+        for pesel in PESELS {
+            assert!(set.insert(&human_redable::Pesel::try_from(pesel).unwrap()));
+        }
+        assert!(!set.insert(&bit_fields::Pesel::try_from(PESELS[0]).unwrap()));
+        assert_eq!(set.len(), 5);
+
+        assert!(set.contains(&bit_fields::Pesel::try_from(PESELS[3]).unwrap()));
+        assert!(!set.contains(&human_redable::Pesel::try_from(02290486113).unwrap()));
+    }
+
+    #[test]
+    fn from_iter_extend() {
+        let mut set: PeselSet = PESELS
+            .iter()
+            .map(|&pesel| human_redable::Pesel::try_from(pesel).unwrap())
+            .collect();
+        assert_eq!(set.len(), 5);
+
+        set.extend(
+            PESELS
+                .iter()
+                .map(|&pesel| bit_fields::Pesel::try_from(pesel).unwrap()),
+        );
+        assert_eq!(set.len(), 5);
+
+        set.extend([human_redable::Pesel::try_from(02290486113).unwrap()]);
+        assert_eq!(set.len(), 6);
+    }
+
+    #[test]
+    #[cfg(feature = "chrono")]
+    fn range_by_birth() {
+        let mut set = PeselSet::new();
+        for pesel in PESELS {
+            set.insert(&bit_fields::Pesel::try_from(pesel).unwrap());
+        }
+
+        let start = NaiveDate::from_ymd_opt(2000, 01, 01).unwrap();
+        let end = NaiveDate::from_ymd_opt(2009, 12, 31).unwrap();
+        assert_eq!(
+            set.range_by_birth(start, end).collect::<Vec<_>>(),
+            [
+                human_redable::Pesel::try_from(01302534699).unwrap(),
+                human_redable::Pesel::try_from(02290486168).unwrap(),
+            ]
+        );
+
+        let start = NaiveDate::from_ymd_opt(1900, 01, 01).unwrap();
+        let end = NaiveDate::from_ymd_opt(2002, 09, 04).unwrap();
+        assert_eq!(set.range_by_birth(start, end).count(), 4);
+        assert_eq!(set.range_by_birth(end, start).count(), 0);
+    }
+}